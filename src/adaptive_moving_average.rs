@@ -25,10 +25,22 @@ pub struct AdaptiveMovingAverage {
     trend_since: Decimal,
 }
 
+/// MESA Adaptive Moving Average
+/// Rocket Science For Traders by John F. Ehlers
+/// fast_limit: largest alpha of the average, default 0.5
+/// slow_limit: smallest alpha of the average, default 0.05
+/// method: dominant cycle measurement of the hilbert transformer, default homodyne
+/// alpha is the fast limit divided by the phase rate of change, so the average
+/// follows price closely when the phase changes quickly
+/// the trend changes when the mama crosses the fama
 impl AdaptiveMovingAverage {
     pub fn new(fast_limit: Decimal, slow_limit: Decimal) -> Self {
+        Self::with_method(fast_limit, slow_limit, CycleMethod::Homodyne)
+    }
+
+    pub fn with_method(fast_limit: Decimal, slow_limit: Decimal, method: CycleMethod) -> Self {
         Self {
-            dsp: DigitalSignalProcessor::new(),
+            dsp: DigitalSignalProcessor::with_method(method),
             fast_limit,
             slow_limit,
             phase: Window::new(2, Decimal::ZERO),
@@ -38,40 +50,6 @@ impl AdaptiveMovingAverage {
         }
     }
 
-    fn price(&self, i: u16) -> Decimal {
-        self.dsp.price(i)
-    }
-    fn smooth(&self, i: u16) -> Decimal {
-        self.dsp.smooth(i)
-    }
-    fn detrender(&self, i: u16) -> Decimal {
-        self.dsp.detrender(i)
-    }
-    fn i1(&self, i: u16) -> Decimal {
-        self.dsp.i1(i)
-    }
-    fn i2(&self, i: u16) -> Decimal {
-        self.dsp.i2(i)
-    }
-    fn q1(&self, i: u16) -> Decimal {
-        self.dsp.q1(i)
-    }
-    fn q2(&self, i: u16) -> Decimal {
-        self.dsp.q2(i)
-    }
-    fn re(&self, i: u16) -> Decimal {
-        self.dsp.re(i)
-    }
-    fn im(&self, i: u16) -> Decimal {
-        self.dsp.im(i)
-    }
-    fn period(&self, i: u16) -> Decimal {
-        self.dsp.period(i)
-    }
-    fn smooth_period(&self, i: u16) -> Decimal {
-        self.dsp.smooth_period(i)
-    }
-
     fn phase(&self, i: u16) -> Decimal {
         *self.phase.get(i).unwrap_or(&Decimal::ZERO)
     }
@@ -83,42 +61,9 @@ impl AdaptiveMovingAverage {
     }
 
     pub fn next(&mut self, price: Decimal) -> ResultSet {
-        let smooth = calculate_smooth(price, self.price(1), self.price(2), self.price(3));
-        let detrender = calculate_detrender(
-            smooth,
-            self.smooth(2),
-            self.smooth(4),
-            self.smooth(6),
-            self.period(1),
-        );
-        let q1 = calculate_q1(
-            detrender,
-            self.detrender(2),
-            self.detrender(4),
-            self.detrender(6),
-            self.period(1),
-        );
-        let i1 = self.detrender(3);
-        let ji = calculate_ji(i1, self.i1(1), self.i1(3), self.i1(5), self.period(1));
-        let jq = calculate_jq(q1, self.q1(1), self.q1(3), self.q1(5), self.period(1));
-        let i2 = calculate_i2(i1, jq, self.i2(1));
-        let q2 = calculate_q2(q1, ji, self.q2(1));
-        let re = calculate_re(i2, self.i2(1), q2, self.q2(1), self.re(1));
-        let im = calculate_im(i2, q2, self.i2(1), self.q2(1), self.im(1));
-        let period = calculate_period(im, re, self.period(1));
-        let smooth_period = calculate_smooth_period(period, self.smooth_period(1));
-
-        self.dsp.price.push(price);
-        self.dsp.smooth.push(smooth);
-        self.dsp.detrender.push(detrender);
-        self.dsp.i1.push(i1);
-        self.dsp.i2.push(i2);
-        self.dsp.q1.push(q1);
-        self.dsp.q2.push(q2);
-        self.dsp.re.push(re);
-        self.dsp.im.push(im);
-        self.dsp.period.push(period);
-        self.dsp.smooth_period.push(smooth_period);
+        let smooth_period = self.dsp.next(price);
+        let i1 = self.dsp.i1(1);
+        let q1 = self.dsp.q1(1);
 
         let mut phase = Decimal::ZERO;
         if i1 != Decimal::ZERO {
@@ -171,7 +116,50 @@ impl AdaptiveMovingAverage {
         result_set.insert("trend".to_string(), self.trend);
         result_set.insert("trend_since".to_string(), self.trend_since);
         result_set.insert("strength".to_string(), strength.to_percent());
+        result_set.insert("period".to_string(), smooth_period.to_quantity());
 
         result_set
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_mama_measures_sine_period() {
+        let mut mama = AdaptiveMovingAverage::new(dec!(0.5), dec!(0.05));
+
+        let mut result = ResultSet::new();
+        for i in 0..300 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            result = mama.next(Decimal::from_f64(price).unwrap());
+        }
+
+        assert!(result["period"] > dec!(18) && result["period"] < dec!(22));
+        assert!((result["mama"] - dec!(100)).abs() < dec!(5));
+    }
+
+    #[test]
+    fn test_mama_with_configured_periodogram() {
+        let mut mama = AdaptiveMovingAverage::with_method(
+            dec!(0.5),
+            dec!(0.05),
+            CycleMethod::AutocorrelationPeriodogram {
+                min_period: 12,
+                max_period: 30,
+                avg_length: 3,
+                decay: dec!(0.99),
+            },
+        );
+
+        let mut result = ResultSet::new();
+        for i in 0..300 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            result = mama.next(Decimal::from_f64(price).unwrap());
+        }
+
+        assert!(result["period"] > dec!(18) && result["period"] < dec!(22));
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::digital_signal_processor::{calculate_adaptive_length, DigitalSignalProcessor};
+use rust_decimal::Decimal;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct AdaptiveRelativeStrengthIndex {
+    fraction: Decimal,
+    min_length: u16,
+    max_length: u16,
+    dsp: DigitalSignalProcessor,
+    price: Window<Decimal>,
+    current_bar: u16,
+    upper_cross: Cross,
+    lower_cross: Cross,
+}
+
+/// Cycle Adaptive RSI
+/// Rocket Science for Traders by John F. Ehlers
+/// fraction: portion of the dominant cycle used as the lookback, default 0.5
+/// min_length: shortest lookback, at least 1, default 4
+/// max_length: longest lookback, at least min_length, default 50
+/// the lookback is set each bar from the smoothed dominant cycle period measured
+/// by the homodyne discriminator, so the rsi is always tuned to the cycle
+/// above 0.7 the market is overbought, below 0.3 it is oversold
+impl AdaptiveRelativeStrengthIndex {
+    pub fn new(fraction: Decimal, min_length: u16, max_length: u16) -> Self {
+        let min_length = min_length.max(1);
+        let max_length = max_length.max(min_length);
+
+        Self {
+            fraction,
+            min_length,
+            max_length,
+            dsp: DigitalSignalProcessor::new(),
+            price: Window::new(max_length + 1, Decimal::ZERO),
+            current_bar: 0,
+            upper_cross: Cross::default(),
+            lower_cross: Cross::default(),
+        }
+    }
+
+    fn price(&self, i: u16) -> Decimal {
+        *self.price.get(i).unwrap_or(&Decimal::ZERO)
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let smooth_period = self.dsp.next(price);
+        let length = calculate_adaptive_length(
+            smooth_period,
+            self.fraction,
+            self.min_length,
+            self.max_length,
+        );
+
+        self.current_bar = self.current_bar.saturating_add(1);
+        self.price.push(price);
+
+        let mut cu = Decimal::ZERO;
+        let mut cd = Decimal::ZERO;
+        for count in 0..length.min(self.current_bar - 1) {
+            let change = self.price(count) - self.price(count + 1);
+            if change > Decimal::ZERO {
+                cu += change;
+            } else {
+                cd -= change;
+            }
+        }
+
+        let rsi = cu.checked_div(cu + cd).unwrap_or(Decimal::ZERO);
+
+        let upper_cross: f64 = self
+            .upper_cross
+            .next(&(ValueType::try_from(rsi).unwrap(), 0.7))
+            .analog()
+            .into();
+        let lower_cross: f64 = self
+            .lower_cross
+            .next(&(ValueType::try_from(rsi).unwrap(), 0.3))
+            .analog()
+            .into();
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("rsi".to_string(), rsi.to_quantity());
+        result_set.insert("length".to_string(), Decimal::from(length));
+        result_set.insert(
+            "upper_cross".to_string(),
+            Decimal::try_from(upper_cross).unwrap(),
+        );
+        result_set.insert(
+            "lower_cross".to_string(),
+            Decimal::try_from(lower_cross).unwrap(),
+        );
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_adaptive_rsi_length() {
+        let mut rsi = AdaptiveRelativeStrengthIndex::new(dec!(0.5), 4, 50);
+
+        let mut result = ResultSet::new();
+        for i in 0..200 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            result = rsi.next(Decimal::from_f64(price).unwrap());
+            assert!(result["rsi"] >= Decimal::ZERO && result["rsi"] <= Decimal::ONE);
+        }
+
+        assert_eq!(result["length"], dec!(10));
+    }
+}
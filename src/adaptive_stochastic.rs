@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::digital_signal_processor::{calculate_adaptive_length, DigitalSignalProcessor};
+use rust_decimal::Decimal;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct AdaptiveStochastic {
+    fraction: Decimal,
+    min_length: u16,
+    max_length: u16,
+    dsp: DigitalSignalProcessor,
+    price: Window<Decimal>,
+    current_bar: u16,
+    upper_cross: Cross,
+    lower_cross: Cross,
+}
+
+/// Cycle Adaptive Stochastic
+/// Rocket Science for Traders by John F. Ehlers
+/// fraction: portion of the dominant cycle used as the lookback, default 1.0
+/// min_length: shortest lookback, at least 1, default 4
+/// max_length: longest lookback, at least min_length, default 50
+/// the lookback is set each bar from the smoothed dominant cycle period measured
+/// by the homodyne discriminator, so the stochastic is always tuned to the cycle
+/// above 0.8 the market is overbought, below 0.2 it is oversold
+impl AdaptiveStochastic {
+    pub fn new(fraction: Decimal, min_length: u16, max_length: u16) -> Self {
+        let min_length = min_length.max(1);
+        let max_length = max_length.max(min_length);
+
+        Self {
+            fraction,
+            min_length,
+            max_length,
+            dsp: DigitalSignalProcessor::new(),
+            price: Window::new(max_length, Decimal::ZERO),
+            current_bar: 0,
+            upper_cross: Cross::default(),
+            lower_cross: Cross::default(),
+        }
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let smooth_period = self.dsp.next(price);
+        let length = calculate_adaptive_length(
+            smooth_period,
+            self.fraction,
+            self.min_length,
+            self.max_length,
+        );
+
+        self.current_bar = self.current_bar.saturating_add(1);
+        self.price.push(price);
+
+        let bars = length.min(self.current_bar);
+        let highest = (0..bars)
+            .filter_map(|i| self.price.get(i).copied())
+            .max()
+            .unwrap_or(price);
+        let lowest = (0..bars)
+            .filter_map(|i| self.price.get(i).copied())
+            .min()
+            .unwrap_or(price);
+
+        let stochastic = (price - lowest)
+            .checked_div(highest - lowest)
+            .unwrap_or(Decimal::ZERO);
+
+        let upper_cross: f64 = self
+            .upper_cross
+            .next(&(ValueType::try_from(stochastic).unwrap(), 0.8))
+            .analog()
+            .into();
+        let lower_cross: f64 = self
+            .lower_cross
+            .next(&(ValueType::try_from(stochastic).unwrap(), 0.2))
+            .analog()
+            .into();
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("stochastic".to_string(), stochastic.to_quantity());
+        result_set.insert("length".to_string(), Decimal::from(length));
+        result_set.insert(
+            "upper_cross".to_string(),
+            Decimal::try_from(upper_cross).unwrap(),
+        );
+        result_set.insert(
+            "lower_cross".to_string(),
+            Decimal::try_from(lower_cross).unwrap(),
+        );
+
+        result_set
+    }
+}
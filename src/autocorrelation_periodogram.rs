@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::roofing_filter::RoofingFilter;
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use yata::core::Window;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct AutocorrelationPeriodogram {
+    min_period: u16,
+    max_period: u16,
+    avg_length: u16,
+    decay: Decimal,
+    roofing: RoofingFilter,
+    filt: Window<Decimal>,
+    cosine: Vec<Vec<Decimal>>,
+    sine: Vec<Vec<Decimal>>,
+    r: Vec<Decimal>,
+    max_pwr: Decimal,
+    pwr: Vec<Decimal>,
+    dominant_cycle: Decimal,
+}
+
+/// Autocorrelation Periodogram
+/// Cycle Analytics For Traders by John F. Ehlers
+/// min_period: shortest cycle measured, default 10
+/// max_period: longest cycle measured, default 48
+/// avg_length: number of bars in each correlation, 0 uses the lag itself, default 3
+/// decay: automatic gain control decay of the peak power per bar, default 0.995
+/// the roofed price is correlated with itself at every lag and the correlations
+/// are transformed into the power at each period, normalised to the peak power
+/// the dominant cycle is the centre of gravity of the periods with at least half
+/// of the peak power, which is much less noisy than the homodyne discriminator
+/// select it as the period source of the adaptive moving average and the signal
+/// to noise ratio with `CycleMethod::AutocorrelationPeriodogram` and these settings
+impl AutocorrelationPeriodogram {
+    pub fn new(min_period: u16, max_period: u16, avg_length: u16, decay: Decimal) -> Self {
+        let mut cosine = Vec::new();
+        let mut sine = Vec::new();
+        for period in min_period..=max_period {
+            let angles = (0..=max_period)
+                .map(|n| Decimal::TWO * Decimal::PI * Decimal::from(n) / Decimal::from(period));
+            cosine.push(angles.clone().map(|angle| angle.cos()).collect());
+            sine.push(angles.map(|angle| angle.sin()).collect());
+        }
+
+        let periods = (max_period - min_period + 1) as usize;
+
+        Self {
+            min_period,
+            max_period,
+            avg_length,
+            decay,
+            roofing: RoofingFilter::new(max_period, min_period),
+            filt: Window::new(2 * max_period + avg_length, Decimal::ZERO),
+            cosine,
+            sine,
+            r: vec![Decimal::ZERO; periods],
+            max_pwr: Decimal::ZERO,
+            pwr: vec![Decimal::ZERO; periods],
+            dominant_cycle: Decimal::from(min_period),
+        }
+    }
+
+    fn filt(&self, i: u16) -> Decimal {
+        *self.filt.get(i).unwrap_or(&Decimal::ZERO)
+    }
+
+    /// Advances the periodogram by one bar and returns the unrounded dominant
+    /// cycle, for indicators that use it as their period source.
+    pub fn dominant_cycle(&mut self, price: Decimal) -> Decimal {
+        let filt = self.roofing.roof(price);
+        self.filt.push(filt);
+
+        let mut corr = vec![Decimal::ZERO; self.max_period as usize + 1];
+        for lag in 0..=self.max_period {
+            let m = if self.avg_length == 0 {
+                lag
+            } else {
+                self.avg_length
+            };
+            let mut sx = Decimal::ZERO;
+            let mut sy = Decimal::ZERO;
+            let mut sxx = Decimal::ZERO;
+            let mut syy = Decimal::ZERO;
+            let mut sxy = Decimal::ZERO;
+            for count in 0..m {
+                let x = self.filt(count);
+                let y = self.filt(lag + count);
+                sx += x;
+                sy += y;
+                sxx += x * x;
+                syy += y * y;
+                sxy += x * y;
+            }
+            let m = Decimal::from(m);
+            let denom = (m * sxx - sx * sx) * (m * syy - sy * sy);
+            if denom > Decimal::ZERO {
+                corr[lag as usize] = (m * sxy - sx * sy) / denom.sqrt().unwrap();
+            }
+        }
+
+        for (index, r) in self.r.iter_mut().enumerate() {
+            let mut cosine_part = Decimal::ZERO;
+            let mut sine_part = Decimal::ZERO;
+            for (n, corr) in corr.iter().enumerate().skip(3) {
+                cosine_part += corr * self.cosine[index][n];
+                sine_part += corr * self.sine[index][n];
+            }
+            let sq_sum = cosine_part * cosine_part + sine_part * sine_part;
+            *r = dec!(0.2) * sq_sum * sq_sum + dec!(0.8) * *r;
+        }
+
+        self.max_pwr *= self.decay;
+        for r in &self.r {
+            if *r > self.max_pwr {
+                self.max_pwr = *r;
+            }
+        }
+
+        let mut spx = Decimal::ZERO;
+        let mut sp = Decimal::ZERO;
+        for (index, pwr) in self.pwr.iter_mut().enumerate() {
+            *pwr = self.r[index]
+                .checked_div(self.max_pwr)
+                .unwrap_or(Decimal::ZERO);
+            if *pwr >= dec!(0.5) {
+                spx += Decimal::from(self.min_period as usize + index) * *pwr;
+                sp += *pwr;
+            }
+        }
+
+        if sp != Decimal::ZERO {
+            self.dominant_cycle = spx / sp;
+        }
+
+        self.dominant_cycle
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let dominant_cycle = self.dominant_cycle(price);
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("dominant_cycle".to_string(), dominant_cycle.to_quantity());
+        for (index, pwr) in self.pwr.iter().enumerate() {
+            let period = self.min_period as usize + index;
+            result_set.insert(format!("power_{}", period), pwr.to_quantity());
+        }
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_autocorrelation_periodogram_dominant_cycle() {
+        let mut periodogram = AutocorrelationPeriodogram::new(10, 48, 3, dec!(0.995));
+
+        let mut result = ResultSet::new();
+        for i in 0..200 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            result = periodogram.next(Decimal::from_f64(price).unwrap());
+        }
+
+        assert!(result["dominant_cycle"] > dec!(18.0) && result["dominant_cycle"] < dec!(22.0));
+        assert!(result["power_20"] > dec!(0.9));
+    }
+}
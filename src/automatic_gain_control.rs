@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use rust_decimal::Decimal;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct AutomaticGainControl {
+    decay: Decimal,
+    peak: Decimal,
+}
+
+/// Automatic Gain Control
+/// Cycle Analytics For Traders by John F. Ehlers
+/// decay: fraction of the peak kept each bar, default 0.991
+/// the peak follows the largest absolute value seen, decaying slowly so that it
+/// adapts to changes in volatility, and the value is divided by the peak so that
+/// the output swings between -1 and 1 whatever the scale of the input
+///
+/// Feed it the output field of another indicator, for example
+/// `agc.next(emd.next(price)["mean"])` or `agc.next(coint.next(x, y)["spread_dyn"])`
+impl AutomaticGainControl {
+    pub fn new(decay: Decimal) -> Self {
+        Self {
+            decay,
+            peak: Decimal::ZERO,
+        }
+    }
+
+    /// Advances the peak by one bar and returns the unrounded normalised value.
+    pub fn normalise(&mut self, value: Decimal) -> Decimal {
+        self.peak *= self.decay;
+        if value.abs() > self.peak {
+            self.peak = value.abs();
+        }
+
+        value.checked_div(self.peak).unwrap_or(Decimal::ZERO)
+    }
+
+    pub fn next(&mut self, value: Decimal) -> ResultSet {
+        let agc = self.normalise(value);
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("agc".to_string(), agc.to_quantity());
+        result_set.insert("peak".to_string(), self.peak.to_quantity());
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_automatic_gain_control() {
+        let mut agc = AutomaticGainControl::new(dec!(0.5));
+
+        assert_eq!(agc.next(dec!(0))["agc"], dec!(0));
+        assert_eq!(agc.next(dec!(-40))["agc"], dec!(-1));
+        assert_eq!(agc.next(dec!(10))["agc"], dec!(0.5));
+        assert_eq!(agc.next(dec!(10))["agc"], dec!(1));
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::digital_signal_processor::DigitalSignalProcessor;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Default)]
+pub struct CenterOfGravity {
+    length: u16,
+    dsp: Option<DigitalSignalProcessor>,
+    price: Window<Decimal>,
+    cg: Window<Decimal>,
+    cross: Cross,
+    trend: Decimal,
+    trend_since: Decimal,
+}
+
+/// Center of Gravity Oscillator
+/// Cybernetic Analysis for Stocks and Futures by John F. Ehlers
+/// length: number of bars in the weighted sum, at least 1, default 10
+/// the oscillator is the balance point of prices over the length, moving
+/// with essentially zero lag so that turning points are identified early
+/// buy when the cg crosses over the trigger, the cg delayed by one bar
+///
+/// Adaptive Center of Gravity
+/// Cybernetic Analysis for Stocks and Futures by John F. Ehlers
+/// the length is set each bar to half the smoothed dominant cycle period
+/// measured by the homodyne discriminator, up to 25 bars
+impl CenterOfGravity {
+    pub fn new(length: u16) -> Self {
+        let length = length.max(1);
+
+        Self {
+            length,
+            price: Window::new(length, Decimal::ZERO),
+            cg: Window::new(1, Decimal::ZERO),
+            ..Default::default()
+        }
+    }
+
+    pub fn adaptive() -> Self {
+        Self {
+            dsp: Some(DigitalSignalProcessor::new()),
+            ..Self::new(25)
+        }
+    }
+
+    fn cg(&self, i: u16) -> Decimal {
+        *self.cg.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let period = self.dsp.as_mut().map(|dsp| dsp.next(price));
+        let length = match period {
+            Some(period) => {
+                ((period / Decimal::TWO).trunc().to_u16().unwrap()).clamp(1, self.length)
+            }
+            None => self.length,
+        };
+
+        self.price.push(price);
+
+        let mut num = Decimal::ZERO;
+        let mut denom = Decimal::ZERO;
+        for count in 0..length {
+            let price = *self.price.get(count).unwrap_or(&Decimal::ZERO);
+            num += Decimal::from(count + 1) * price;
+            denom += price;
+        }
+
+        let cg = if denom != Decimal::ZERO {
+            -num / denom + Decimal::from(length + 1) / Decimal::TWO
+        } else {
+            Decimal::ZERO
+        };
+
+        let trigger = self.cg(1);
+
+        self.cg.push(cg);
+
+        let cross: Decimal = self
+            .cross
+            .next(&(
+                ValueType::try_from(cg).unwrap(),
+                ValueType::try_from(trigger).unwrap(),
+            ))
+            .analog()
+            .into();
+
+        if cross == Decimal::ZERO {
+            self.trend_since += Decimal::ONE;
+        } else {
+            self.trend = cross;
+            self.trend_since = Decimal::ZERO;
+        }
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("cg".to_string(), cg.to_quantity());
+        result_set.insert("trigger".to_string(), trigger.to_quantity());
+        result_set.insert("cross".to_string(), cross);
+        result_set.insert("trend".to_string(), self.trend);
+        result_set.insert("trend_since".to_string(), self.trend_since);
+        if period.is_some() {
+            result_set.insert("length".to_string(), Decimal::from(length));
+        }
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+    use rust_decimal_macros::dec;
+
+    fn sine(i: usize) -> Decimal {
+        Decimal::from_f64(100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_center_of_gravity_turning_points() {
+        let mut cg = CenterOfGravity::new(10);
+
+        let mut total = Decimal::ZERO;
+        let mut crosses = 0;
+        for i in 0..200 {
+            let result = cg.next(sine(i));
+            if i < 100 {
+                continue;
+            }
+
+            total += result["cg"];
+            if result["cross"] == Decimal::ONE {
+                assert!((15..=17).contains(&(i % 20)), "cross over at {}", i);
+                crosses += 1;
+            }
+            if result["cross"] == -Decimal::ONE {
+                assert!((4..=6).contains(&(i % 20)), "cross under at {}", i);
+                crosses += 1;
+            }
+        }
+
+        assert!((total / dec!(100)).abs() < dec!(0.01));
+        assert_eq!(crosses, 10);
+        assert_eq!(CenterOfGravity::new(0).next(dec!(100))["cg"], Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_adaptive_center_of_gravity_length() {
+        let mut cg = CenterOfGravity::adaptive();
+
+        for i in 0..200 {
+            let result = cg.next(sine(i));
+            if i >= 150 {
+                assert!(result["length"] >= dec!(9) && result["length"] <= dec!(11));
+            }
+        }
+    }
+}
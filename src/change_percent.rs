@@ -5,6 +5,12 @@ pub struct ChangePercent {
     prev: Option<Decimal>,
 }
 
+impl Default for ChangePercent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ChangePercent {
     pub fn new() -> Self {
         Self {
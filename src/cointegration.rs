@@ -34,13 +34,13 @@ impl Cointegration {
         let spread_std: Vec<f64> = spread_standard(&x, &y).unwrap();
         result.insert(
             "spread_std".to_string(),
-            Decimal::from_f64(spread_std.last().unwrap().clone()).unwrap(),
+            Decimal::from_f64(*spread_std.last().unwrap()).unwrap(),
         );
 
         let spread_dyn: Vec<f64> = spread_dynamic(&x, &y).unwrap();
         result.insert(
             "spread_dyn".to_string(),
-            Decimal::from_f64(spread_dyn.last().unwrap().clone()).unwrap(),
+            Decimal::from_f64(*spread_dyn.last().unwrap()).unwrap(),
         );
 
         let e_coint = engle_granger_cointegration_test(&x, &y);
@@ -102,7 +102,7 @@ mod tests {
 
     #[test]
     fn test_cointegration_next() {
-        let mut cointegration = Cointegration::new();
+        let mut cointegration = Cointegration::new(23);
 
         let series_1 = vec![
             0.5638, 0.5519, 0.557, 0.5571, 0.5577, 0.5547, 0.5581, 0.5582, 0.5577, 0.5617, 0.5656,
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::empirical_mode_decomposition::{calculate_band_pass, calculate_band_pass_coefficients};
+use crate::roofing_filter::RoofingFilter;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use yata::core::Window;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct CombFilterSpectrum {
+    min_period: u16,
+    decay: Decimal,
+    roofing: RoofingFilter,
+    filt: Window<Decimal>,
+    coefficients: Vec<(Decimal, Decimal)>,
+    bp: Vec<Window<Decimal>>,
+    max_pwr: Decimal,
+    pwr: Vec<Decimal>,
+    dominant_cycle: Decimal,
+}
+
+/// Comb Filter Spectral Estimate
+/// Cycle Analytics For Traders by John F. Ehlers
+/// min_period: shortest cycle measured, default 10
+/// max_period: longest cycle measured, default 48
+/// bandwidth: fractional bandwidth of each band pass filter, default 0.3
+/// decay: automatic gain control decay of the peak power per bar, default 0.995
+/// the roofed price is passed through a bank of band pass filters, one centred on
+/// each period, and the power of each filter over one period is normalised to the
+/// peak power
+/// the dominant cycle is the centre of gravity of the periods with at least half
+/// of the peak power
+impl CombFilterSpectrum {
+    pub fn new(min_period: u16, max_period: u16, bandwidth: Decimal, decay: Decimal) -> Self {
+        let coefficients = (min_period..=max_period)
+            .map(|period| {
+                calculate_band_pass_coefficients(Decimal::from(period), bandwidth / Decimal::TWO)
+            })
+            .collect();
+        let bp = (min_period..=max_period)
+            .map(|period| Window::new(period, Decimal::ZERO))
+            .collect();
+
+        let periods = (max_period - min_period + 1) as usize;
+
+        Self {
+            min_period,
+            decay,
+            roofing: RoofingFilter::new(max_period, min_period),
+            filt: Window::new(2, Decimal::ZERO),
+            coefficients,
+            bp,
+            max_pwr: Decimal::ZERO,
+            pwr: vec![Decimal::ZERO; periods],
+            dominant_cycle: Decimal::from(min_period),
+        }
+    }
+
+    fn filt(&self, i: u16) -> Decimal {
+        *self.filt.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    /// Advances the filter bank by one bar and returns the unrounded dominant
+    /// cycle, for indicators that use it as their period source.
+    pub fn dominant_cycle(&mut self, price: Decimal) -> Decimal {
+        let filt = self.roofing.roof(price);
+        let filt2 = self.filt(2);
+        self.filt.push(filt);
+
+        self.max_pwr *= self.decay;
+        for (index, bp) in self.bp.iter_mut().enumerate() {
+            let (alpha, beta) = self.coefficients[index];
+            let bp1 = *bp.get(0).unwrap_or(&Decimal::ZERO);
+            let bp2 = *bp.get(1).unwrap_or(&Decimal::ZERO);
+            bp.push(calculate_band_pass(filt, filt2, bp1, bp2, alpha, beta));
+
+            let period = Decimal::from(bp.len());
+            let mut pwr = Decimal::ZERO;
+            for value in bp.iter() {
+                pwr += (value / period) * (value / period);
+            }
+            self.pwr[index] = pwr;
+
+            if pwr > self.max_pwr {
+                self.max_pwr = pwr;
+            }
+        }
+
+        let mut spx = Decimal::ZERO;
+        let mut sp = Decimal::ZERO;
+        for (index, pwr) in self.pwr.iter_mut().enumerate() {
+            *pwr = pwr.checked_div(self.max_pwr).unwrap_or(Decimal::ZERO);
+            if *pwr >= dec!(0.5) {
+                spx += Decimal::from(self.min_period as usize + index) * *pwr;
+                sp += *pwr;
+            }
+        }
+
+        if sp != Decimal::ZERO {
+            self.dominant_cycle = spx / sp;
+        }
+
+        self.dominant_cycle
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let dominant_cycle = self.dominant_cycle(price);
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("dominant_cycle".to_string(), dominant_cycle.to_quantity());
+        for (index, pwr) in self.pwr.iter().enumerate() {
+            let period = self.min_period as usize + index;
+            result_set.insert(format!("power_{}", period), pwr.to_quantity());
+        }
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_comb_filter_spectrum_dominant_cycle() {
+        let mut comb = CombFilterSpectrum::new(10, 48, dec!(0.3), dec!(0.995));
+
+        let mut result = ResultSet::new();
+        for i in 0..600 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            result = comb.next(Decimal::from_f64(price).unwrap());
+        }
+
+        assert!(result["dominant_cycle"] > dec!(18.0) && result["dominant_cycle"] < dec!(22.0));
+    }
+}
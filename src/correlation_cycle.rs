@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use crate::correlation_trend_indicator::calculate_correlation;
+use crate::decimal::DecimalExt;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use yata::core::Window;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct CorrelationCycle {
+    period: u16,
+    threshold: Decimal,
+    current_bar: u16,
+    price: Window<Decimal>,
+    cosine: Vec<Decimal>,
+    sine: Vec<Decimal>,
+    angle: Decimal,
+    trend: i64,
+    trend_since: i64,
+}
+
+/// Correlation Cycle
+/// Correlation As A Cycle Indicator, Stocks & Commodities June 2020 by John F. Ehlers
+/// period: period of the cycle correlated against, default 20
+/// threshold: largest change of phase angle per bar in a trend, default 9 degrees
+/// real: correlation of price with a cosine wave over one period
+/// imag: correlation of price with a negative sine wave over one period
+/// angle: phase angle of the (real, imag) phasor, which is not allowed to go backwards
+///
+/// In a cycle the angle advances at 360 / period degrees per bar. When it stalls
+/// the market is in a trend, up when the angle is positive, down when negative.
+/// trend: 1 in an uptrend, -1 in a downtrend, 0 in cycle mode
+/// until a full period has been received only those bars are correlated and the
+/// market is held in cycle mode
+impl CorrelationCycle {
+    pub fn new(period: u16, threshold: Decimal) -> Self {
+        let angles = (0..period)
+            .map(|count| Decimal::TWO * Decimal::PI * Decimal::from(count) / Decimal::from(period));
+
+        Self {
+            period,
+            threshold,
+            current_bar: 0,
+            price: Window::new(period, Decimal::ZERO),
+            cosine: angles.clone().map(|angle| angle.cos()).collect(),
+            sine: angles.map(|angle| -angle.sin()).collect(),
+            angle: Decimal::ZERO,
+            trend: 0,
+            trend_since: 0,
+        }
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        self.current_bar = self.current_bar.saturating_add(1);
+        self.price.push(price);
+
+        let bars = self.current_bar.min(self.period);
+        let x: Vec<Decimal> = (0..bars)
+            .map(|count| *self.price.get(count).unwrap_or(&Decimal::ZERO))
+            .collect();
+        let real = calculate_correlation(&x, &self.cosine[..bars as usize]);
+        let imag = calculate_correlation(&x, &self.sine[..bars as usize]);
+
+        let angle1 = self.angle;
+        let mut angle = angle1;
+        if imag != Decimal::ZERO {
+            angle = dec!(90.0)
+                + Decimal::try_from(
+                    (real.to_f64().unwrap() / imag.to_f64().unwrap())
+                        .atan()
+                        .to_degrees(),
+                )
+                .unwrap();
+            if imag > Decimal::ZERO {
+                angle -= dec!(180.0);
+            }
+        }
+        if angle1 - angle < dec!(270.0) && angle < angle1 {
+            angle = angle1;
+        }
+        self.angle = angle;
+
+        let trend = if self.current_bar < self.period {
+            0
+        } else if (angle - angle1).abs() < self.threshold {
+            if angle < Decimal::ZERO {
+                -1
+            } else {
+                1
+            }
+        } else {
+            0
+        };
+
+        if trend == self.trend {
+            self.trend_since += 1;
+        } else {
+            self.trend = trend;
+            self.trend_since = 0;
+        }
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("real".to_string(), real.to_quantity());
+        result_set.insert("imag".to_string(), imag.to_quantity());
+        result_set.insert("angle".to_string(), angle.to_quantity());
+        result_set.insert("trend".to_string(), Decimal::from(self.trend));
+        result_set.insert("trend_since".to_string(), Decimal::from(self.trend_since));
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_correlation_cycle_market_state() {
+        let mut correlation_cycle = CorrelationCycle::new(20, dec!(9.0));
+
+        let mut result = ResultSet::new();
+        for i in 0..100 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            result = correlation_cycle.next(Decimal::from_f64(price).unwrap());
+        }
+        assert_eq!(result["trend"], Decimal::ZERO);
+
+        for i in 0..40 {
+            result = correlation_cycle.next(Decimal::from(100 + i));
+        }
+        assert_eq!(result["trend"], Decimal::ONE);
+    }
+
+    #[test]
+    fn test_correlation_cycle_warm_up() {
+        let mut correlation_cycle = CorrelationCycle::new(20, dec!(9.0));
+
+        for i in 0..19 {
+            let result = correlation_cycle.next(Decimal::from(200 - i));
+            assert_eq!(result["trend"], Decimal::ZERO);
+        }
+
+        let mut result = ResultSet::new();
+        for i in 19..60 {
+            result = correlation_cycle.next(Decimal::from(200 - i));
+        }
+        assert_eq!(result["trend"], -Decimal::ONE);
+    }
+}
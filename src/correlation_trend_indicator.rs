@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use rust_decimal::{Decimal, MathematicalOps};
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Default)]
+pub struct CorrelationTrendIndicator {
+    length: u16,
+    current_bar: u16,
+    price: Window<Decimal>,
+    cross: Cross,
+    trend: Decimal,
+    trend_since: Decimal,
+}
+
+/// Correlation Trend Indicator
+/// Correlation As A Trend Indicator, Stocks & Commodities May 2020 by John F. Ehlers
+/// length: number of bars correlated against a straight line, default 20
+/// the correlation is near 1 when prices rise in a straight line over the length
+/// and near -1 when they fall in a straight line
+/// until length bars have been received only those bars are correlated
+/// the trend changes when the correlation crosses zero
+impl CorrelationTrendIndicator {
+    pub fn new(length: u16) -> Self {
+        Self {
+            length,
+            price: Window::new(length, Decimal::ZERO),
+            ..Default::default()
+        }
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        self.current_bar = self.current_bar.saturating_add(1);
+        self.price.push(price);
+
+        let bars = self.current_bar.min(self.length);
+        let x: Vec<Decimal> = (0..bars)
+            .map(|count| *self.price.get(count).unwrap_or(&Decimal::ZERO))
+            .collect();
+        let y: Vec<Decimal> = (0..bars).map(|count| -Decimal::from(count + 1)).collect();
+        let correlation = calculate_correlation(&x, &y);
+
+        let cross: Decimal = self
+            .cross
+            .next(&(ValueType::try_from(correlation).unwrap(), 0.0))
+            .analog()
+            .into();
+
+        if cross == Decimal::ZERO {
+            self.trend_since += Decimal::ONE;
+        } else {
+            self.trend = cross;
+            self.trend_since = Decimal::ZERO;
+        }
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("correlation".to_string(), correlation.to_quantity());
+        result_set.insert("cross".to_string(), cross);
+        result_set.insert("trend".to_string(), self.trend);
+        result_set.insert("trend_since".to_string(), self.trend_since);
+
+        result_set
+    }
+}
+
+/// Pearson correlation coefficient of two equal length series, zero when
+/// either series is flat
+pub fn calculate_correlation(x: &[Decimal], y: &[Decimal]) -> Decimal {
+    let n = Decimal::from(x.len());
+    let mut sx = Decimal::ZERO;
+    let mut sy = Decimal::ZERO;
+    let mut sxx = Decimal::ZERO;
+    let mut sxy = Decimal::ZERO;
+    let mut syy = Decimal::ZERO;
+    for (x, y) in x.iter().zip(y) {
+        sx += x;
+        sy += y;
+        sxx += x * x;
+        sxy += x * y;
+        syy += y * y;
+    }
+
+    let var_x = n * sxx - sx * sx;
+    let var_y = n * syy - sy * sy;
+    if var_x > Decimal::ZERO && var_y > Decimal::ZERO {
+        (n * sxy - sx * sy) / (var_x * var_y).sqrt().unwrap()
+    } else {
+        Decimal::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_correlation_trend_indicator() {
+        let mut cti = CorrelationTrendIndicator::new(20);
+
+        let mut result = ResultSet::new();
+        for i in 0..20 {
+            result = cti.next(Decimal::from(100 + i));
+        }
+        assert_eq!(result["correlation"], Decimal::ONE);
+
+        for i in 0..20 {
+            result = cti.next(Decimal::from(120 - i));
+        }
+        assert_eq!(result["correlation"], -Decimal::ONE);
+        assert_eq!(result["trend"], -Decimal::ONE);
+    }
+
+    #[test]
+    fn test_correlation_trend_indicator_warm_up() {
+        let mut cti = CorrelationTrendIndicator::new(20);
+
+        assert_eq!(cti.next(dec!(100))["correlation"], Decimal::ZERO);
+        for i in 1..20 {
+            let result = cti.next(Decimal::from(100 - i));
+            assert_eq!(result["correlation"], -Decimal::ONE);
+        }
+    }
+
+    #[test]
+    fn test_calculate_correlation() {
+        let x = [dec!(1), dec!(2), dec!(3)];
+        assert_eq!(
+            calculate_correlation(&x, &[dec!(2), dec!(4), dec!(6)]),
+            dec!(1)
+        );
+        assert_eq!(
+            calculate_correlation(&x, &[dec!(1), dec!(1), dec!(1)]),
+            dec!(0)
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::digital_signal_processor::DigitalSignalProcessor;
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Default)]
+pub struct CyberCycle {
+    alpha: Decimal,
+    dsp: Option<DigitalSignalProcessor>,
+    price: Window<Decimal>,
+    smooth: Window<Decimal>,
+    cycle: Window<Decimal>,
+    current_bar: usize,
+    cross: Cross,
+    trend: Decimal,
+    trend_since: Decimal,
+}
+
+/// Cyber Cycle
+/// p34 - 36 Cybernetic Analysis for Stocks and Futures by John F. Ehlers
+/// alpha: smoothing of the high pass filter, default 0.07
+/// the cycle component is isolated by high pass filtering a four bar smoothed price
+/// the trigger is the cycle delayed by one bar, buy when the cycle crosses over it
+///
+/// Adaptive Cyber Cycle
+/// p40 - 42 Cybernetic Analysis for Stocks and Futures by John F. Ehlers
+/// alpha is set each bar to 2 / (period + 1) where period is the smoothed
+/// dominant cycle measured by the homodyne discriminator
+impl CyberCycle {
+    pub fn new(alpha: Decimal) -> Self {
+        Self {
+            alpha,
+            price: Window::new(3, Decimal::ZERO),
+            smooth: Window::new(2, Decimal::ZERO),
+            cycle: Window::new(2, Decimal::ZERO),
+            ..Default::default()
+        }
+    }
+
+    pub fn adaptive() -> Self {
+        Self {
+            dsp: Some(DigitalSignalProcessor::new()),
+            ..Self::new(dec!(0.07))
+        }
+    }
+
+    fn price(&self, i: u16) -> Decimal {
+        *self.price.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    fn smooth(&self, i: u16) -> Decimal {
+        *self.smooth.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    fn cycle(&self, i: u16) -> Decimal {
+        *self.cycle.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        self.current_bar += 1;
+
+        let period = self.dsp.as_mut().map(|dsp| dsp.next(price));
+        let alpha = match period {
+            Some(period) => Decimal::TWO / (period + Decimal::ONE),
+            None => self.alpha,
+        };
+
+        let smooth =
+            (price + Decimal::TWO * self.price(1) + Decimal::TWO * self.price(2) + self.price(3))
+                / dec!(6.0);
+
+        let cycle = if self.current_bar < 7 {
+            (price - Decimal::TWO * self.price(1) + self.price(2)) / dec!(4.0)
+        } else {
+            (Decimal::ONE - dec!(0.5) * alpha).powi(2)
+                * (smooth - Decimal::TWO * self.smooth(1) + self.smooth(2))
+                + Decimal::TWO * (Decimal::ONE - alpha) * self.cycle(1)
+                - (Decimal::ONE - alpha).powi(2) * self.cycle(2)
+        };
+
+        let trigger = self.cycle(1);
+
+        self.price.push(price);
+        self.smooth.push(smooth);
+        self.cycle.push(cycle);
+
+        let cross: Decimal = self
+            .cross
+            .next(&(
+                ValueType::try_from(cycle).unwrap(),
+                ValueType::try_from(trigger).unwrap(),
+            ))
+            .analog()
+            .into();
+
+        if cross == Decimal::ZERO {
+            self.trend_since += Decimal::ONE;
+        } else {
+            self.trend = cross;
+            self.trend_since = Decimal::ZERO;
+        }
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("cycle".to_string(), cycle.to_quantity());
+        result_set.insert("trigger".to_string(), trigger.to_quantity());
+        result_set.insert("cross".to_string(), cross);
+        result_set.insert("trend".to_string(), self.trend);
+        result_set.insert("trend_since".to_string(), self.trend_since);
+        if let Some(period) = period {
+            result_set.insert("period".to_string(), period.to_quantity());
+        }
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_adaptive_cyber_cycle_period() {
+        let mut cyber_cycle = CyberCycle::adaptive();
+
+        let mut result = ResultSet::new();
+        for i in 0..200 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            result = cyber_cycle.next(Decimal::from_f64(price).unwrap());
+        }
+
+        assert!(result["period"] > dec!(15.0) && result["period"] < dec!(25.0));
+    }
+}
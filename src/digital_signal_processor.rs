@@ -1,10 +1,38 @@
+use crate::autocorrelation_periodogram::AutocorrelationPeriodogram;
 use rust_decimal::prelude::ToPrimitive;
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, MathematicalOps};
 use rust_decimal_macros::dec;
 use yata::core::Window;
 
+/// Dominant cycle measurement used by the digital signal processor
+/// Rocket Science For Traders by John F. Ehlers
+/// homodyne: the phase change of the smoothed product of the analytic signal with
+/// its value one bar ago
+/// phase_accumulation: the number of bars over which the phase changes by 360 degrees
+/// dual_differentiator: the phase rate of change from the derivatives of the in
+/// phase and quadrature components
+/// hilbert_transform: the instantaneous phase change of the analytic signal between bars
+/// autocorrelation_periodogram: the dominant cycle of an autocorrelation periodogram
+/// with the given settings, Cycle Analytics For Traders by John F. Ehlers, see
+/// AutocorrelationPeriodogram for the defaults of 10, 48, 3 and 0.995
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CycleMethod {
+    #[default]
+    Homodyne,
+    PhaseAccumulation,
+    DualDifferentiator,
+    HilbertTransform,
+    AutocorrelationPeriodogram {
+        min_period: u16,
+        max_period: u16,
+        avg_length: u16,
+        decay: Decimal,
+    },
+}
+
 #[derive(Default)]
 pub struct DigitalSignalProcessor {
+    pub method: CycleMethod,
     pub price: Window<Decimal>,
     pub smooth: Window<Decimal>,
     pub detrender: Window<Decimal>,
@@ -17,11 +45,19 @@ pub struct DigitalSignalProcessor {
     pub im: Window<Decimal>,
     pub period: Window<Decimal>,
     pub smooth_period: Window<Decimal>,
+    pub phase: Window<Decimal>,
+    pub delta_phase: Window<Decimal>,
+    pub periodogram: Option<AutocorrelationPeriodogram>,
 }
 
 impl DigitalSignalProcessor {
     pub fn new() -> Self {
+        Self::with_method(CycleMethod::Homodyne)
+    }
+
+    pub fn with_method(method: CycleMethod) -> Self {
         Self {
+            method,
             price: Window::new(4, Decimal::ZERO),
             smooth: Window::new(7, Decimal::ZERO),
             detrender: Window::new(7, Decimal::ZERO),
@@ -34,6 +70,19 @@ impl DigitalSignalProcessor {
             im: Window::new(2, Decimal::ZERO),
             period: Window::new(2, Decimal::ZERO),
             smooth_period: Window::new(2, Decimal::ZERO),
+            phase: Window::new(2, Decimal::ZERO),
+            delta_phase: Window::new(50, Decimal::ZERO),
+            periodogram: match method {
+                CycleMethod::AutocorrelationPeriodogram {
+                    min_period,
+                    max_period,
+                    avg_length,
+                    decay,
+                } => Some(AutocorrelationPeriodogram::new(
+                    min_period, max_period, avg_length, decay,
+                )),
+                _ => None,
+            },
         }
     }
 
@@ -73,6 +122,80 @@ impl DigitalSignalProcessor {
     pub fn q3(&self, i: u16) -> Decimal {
         *self.q3.get(i - 1).unwrap_or(&Decimal::ZERO)
     }
+    pub fn phase(&self, i: u16) -> Decimal {
+        *self.phase.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+    pub fn delta_phase(&self, i: u16) -> Decimal {
+        *self.delta_phase.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    /// Advances the hilbert transformer and the dominant cycle measurement of the
+    /// chosen method by one bar, pushing every stage of the pipeline, and returns
+    /// the smoothed dominant cycle period.
+    pub fn next(&mut self, price: Decimal) -> Decimal {
+        let smooth = calculate_smooth(price, self.price(1), self.price(2), self.price(3));
+        let detrender = calculate_detrender(
+            smooth,
+            self.smooth(2),
+            self.smooth(4),
+            self.smooth(6),
+            self.period(1),
+        );
+        let q1 = calculate_q1(
+            detrender,
+            self.detrender(2),
+            self.detrender(4),
+            self.detrender(6),
+            self.period(1),
+        );
+        let i1 = self.detrender(3);
+        let ji = calculate_ji(i1, self.i1(1), self.i1(3), self.i1(5), self.period(1));
+        let jq = calculate_jq(q1, self.q1(1), self.q1(3), self.q1(5), self.period(1));
+        let i2 = calculate_i2(i1, jq, self.i2(1));
+        let q2 = calculate_q2(q1, ji, self.q2(1));
+        let re = calculate_re(i2, self.i2(1), q2, self.q2(1), self.re(1));
+        let im = calculate_im(i2, q2, self.i2(1), self.q2(1), self.im(1));
+        let phase = calculate_phase(i2, q2);
+        let delta_phase = calculate_delta_phase(phase, self.phase(1));
+        let period = match self.method {
+            CycleMethod::Homodyne => calculate_period(im, re, self.period(1)),
+            CycleMethod::PhaseAccumulation => {
+                let delta_phases: Vec<Decimal> = std::iter::once(delta_phase)
+                    .chain((1..self.delta_phase.len()).map(|i| self.delta_phase(i)))
+                    .collect();
+                calculate_accumulated_period(&delta_phases, self.period(1))
+            }
+            CycleMethod::DualDifferentiator => {
+                calculate_differentiated_period(i2, self.i2(1), q2, self.q2(1), self.period(1))
+            }
+            CycleMethod::HilbertTransform => {
+                calculate_instantaneous_period(delta_phase, self.period(1))
+            }
+            CycleMethod::AutocorrelationPeriodogram { .. } => match self.periodogram.as_mut() {
+                Some(periodogram) => periodogram.dominant_cycle(price),
+                None => self.period(1),
+            },
+        };
+        let smooth_period = calculate_smooth_period(period, self.smooth_period(1));
+        let q3 = calculate_q3(smooth, self.smooth(2), smooth_period);
+
+        self.price.push(price);
+        self.smooth.push(smooth);
+        self.detrender.push(detrender);
+        self.i1.push(i1);
+        self.i2.push(i2);
+        self.q1.push(q1);
+        self.q2.push(q2);
+        self.q3.push(q3);
+        self.re.push(re);
+        self.im.push(im);
+        self.period.push(period);
+        self.smooth_period.push(smooth_period);
+        self.phase.push(phase);
+        self.delta_phase.push(delta_phase);
+
+        smooth_period
+    }
 }
 
 pub fn calculate_smooth(
@@ -160,12 +283,23 @@ pub fn calculate_im(
     dec!(0.2) * (i2 * q2_1 - q2 * i2_1) + dec!(0.8) * im_1
 }
 
+/// Homodyne period in bars, a full cycle of 2 pi divided by the phase change
+/// atan(im / re) in radians per bar, limited and smoothed with the previous period
 pub fn calculate_period(im: Decimal, re: Decimal, period_1: Decimal) -> Decimal {
-    let mut period = if im != Decimal::ZERO && re != Decimal::ZERO {
-        Decimal::try_from(360.0 / (im.to_f64().unwrap() / re.to_f64().unwrap()).atan()).unwrap()
+    let period = if im != Decimal::ZERO && re != Decimal::ZERO {
+        Decimal::try_from(
+            2.0 * std::f64::consts::PI / (im.to_f64().unwrap() / re.to_f64().unwrap()).atan(),
+        )
+        .unwrap()
     } else {
         Decimal::ZERO
     };
+    calculate_limited_period(period, period_1)
+}
+
+/// Limits the measured period to within 0.67 and 1.5 times the previous period
+/// and to 6 to 50 bars, then smooths it with the previous period
+pub fn calculate_limited_period(mut period: Decimal, period_1: Decimal) -> Decimal {
     if period > dec!(1.5) * period_1 {
         period = dec!(1.5) * period_1;
     }
@@ -181,6 +315,61 @@ pub fn calculate_period(im: Decimal, re: Decimal, period_1: Decimal) -> Decimal
     dec!(0.2) * period + dec!(0.8) * period_1
 }
 
+/// Phase change in degrees since the previous bar, wrapped to -180 to 180
+pub fn calculate_delta_phase(phase: Decimal, phase_1: Decimal) -> Decimal {
+    let mut delta_phase = phase - phase_1;
+    if delta_phase > dec!(180.0) {
+        delta_phase -= dec!(360.0);
+    }
+    if delta_phase < dec!(-180.0) {
+        delta_phase += dec!(360.0);
+    }
+    delta_phase
+}
+
+/// Number of bars, newest delta phase first, over which the phase changes by
+/// 360 degrees, each delta phase limited to 1 to 60 degrees
+pub fn calculate_accumulated_period(delta_phases: &[Decimal], period_1: Decimal) -> Decimal {
+    let mut total = Decimal::ZERO;
+    let mut inst_period = period_1;
+    for (count, delta_phase) in delta_phases.iter().enumerate() {
+        total += delta_phase.clamp(&Decimal::ONE, &dec!(60.0));
+        if total > dec!(360.0) {
+            inst_period = Decimal::from(count);
+            break;
+        }
+    }
+    calculate_limited_period(inst_period, period_1)
+}
+
+/// Period of the phase rate of change measured from the derivatives of the in
+/// phase and quadrature components, held while the phase does not advance
+pub fn calculate_differentiated_period(
+    i2: Decimal,
+    i2_1: Decimal,
+    q2: Decimal,
+    q2_1: Decimal,
+    period_1: Decimal,
+) -> Decimal {
+    let value = q2 * (i2 - i2_1) - i2 * (q2 - q2_1);
+    let period = if value > Decimal::ZERO {
+        Decimal::TWO * Decimal::PI * (i2 * i2 + q2 * q2) / value
+    } else {
+        period_1
+    };
+    calculate_limited_period(period, period_1)
+}
+
+/// Period of the instantaneous phase change in degrees between two bars
+pub fn calculate_instantaneous_period(delta_phase: Decimal, period_1: Decimal) -> Decimal {
+    let period = if delta_phase > Decimal::ZERO {
+        dec!(360.0) / delta_phase
+    } else {
+        period_1
+    };
+    calculate_limited_period(period, period_1)
+}
+
 pub fn calculate_smooth_period(period: Decimal, smooth_period_1: Decimal) -> Decimal {
     dec!(0.33) * period + dec!(0.67) * smooth_period_1
 }
@@ -201,3 +390,88 @@ pub fn calculate_snr(signal: Decimal, noise: Decimal, snr_1: Decimal) -> Decimal
         Decimal::ZERO
     }
 }
+
+/// Phase angle in degrees, 0 to 360, of the in phase and quadrature components
+pub fn calculate_phase(i1: Decimal, q1: Decimal) -> Decimal {
+    let mut phase = Decimal::try_from(
+        (-q1.to_f64().unwrap())
+            .atan2(i1.to_f64().unwrap())
+            .to_degrees(),
+    )
+    .unwrap();
+    if phase < Decimal::ZERO {
+        phase += dec!(360.0);
+    }
+    phase
+}
+
+/// Phase of the dominant cycle in degrees, 0 to 360, compensated for the seven bar
+/// lag of the smoothing, detrending and in phase delay of the hilbert transformer
+pub fn calculate_sinewave_phase(i1: Decimal, q1: Decimal, smooth_period: Decimal) -> Decimal {
+    let lag = dec!(7.0) * dec!(360.0) / smooth_period.max(dec!(6.0));
+    (calculate_phase(i1, q1) + lag) % dec!(360.0)
+}
+
+/// Sine and lead sine, advanced by 45 degrees, of a phase in degrees
+pub fn calculate_sinewave(phase: Decimal) -> (Decimal, Decimal) {
+    let radians = phase * Decimal::PI / dec!(180.0);
+    (radians.sin(), (radians + Decimal::PI / dec!(4.0)).sin())
+}
+
+/// Lookback of a cycle adaptive indicator, the given fraction of the smoothed
+/// dominant cycle period bounded by min_length and max_length, never less than one bar
+pub fn calculate_adaptive_length(
+    smooth_period: Decimal,
+    fraction: Decimal,
+    min_length: u16,
+    max_length: u16,
+) -> u16 {
+    let min_length = min_length.max(1);
+    let max_length = max_length.max(min_length);
+
+    (fraction * smooth_period)
+        .round()
+        .to_u16()
+        .unwrap_or(min_length)
+        .clamp(min_length, max_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_cycle_methods_measure_sine_period() {
+        for method in [
+            CycleMethod::Homodyne,
+            CycleMethod::PhaseAccumulation,
+            CycleMethod::DualDifferentiator,
+            CycleMethod::HilbertTransform,
+            CycleMethod::AutocorrelationPeriodogram {
+                min_period: 10,
+                max_period: 48,
+                avg_length: 3,
+                decay: dec!(0.995),
+            },
+        ] {
+            for (level, amplitude) in [(100.0, 5.0), (0.55, 0.05)] {
+                let mut dsp = DigitalSignalProcessor::with_method(method);
+
+                let mut smooth_period = Decimal::ZERO;
+                for i in 0..300 {
+                    let price =
+                        level + amplitude * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+                    smooth_period = dsp.next(Decimal::from_f64(price).unwrap());
+                }
+
+                assert!(
+                    smooth_period > dec!(17.0) && smooth_period < dec!(23.0),
+                    "{:?} at {}",
+                    method,
+                    level
+                );
+            }
+        }
+    }
+}
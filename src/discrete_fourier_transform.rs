@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::roofing_filter::RoofingFilter;
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use yata::core::Window;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct DiscreteFourierTransform {
+    min_period: u16,
+    window: u16,
+    roofing: RoofingFilter,
+    filt: Window<Decimal>,
+    cosine: Vec<Vec<Decimal>>,
+    sine: Vec<Vec<Decimal>>,
+    pwr: Vec<Decimal>,
+    db: Vec<Decimal>,
+    dominant_cycle: Decimal,
+}
+
+/// Discrete Fourier Transform Spectral Estimate
+/// Cycle Analytics For Traders by John F. Ehlers
+/// min_period: shortest cycle measured, default 10
+/// max_period: longest cycle measured, also the high pass cutoff, default 48
+/// window: number of roofed bars in the transform, default 48
+/// the power at each period is normalised to the peak power and expressed in
+/// decibels below the peak, clipped at 20dB, for charting as a heatmap
+/// the dominant cycle is the centre of gravity of the periods within 3dB of the peak
+impl DiscreteFourierTransform {
+    pub fn new(min_period: u16, max_period: u16, window: u16) -> Self {
+        let mut cosine = Vec::new();
+        let mut sine = Vec::new();
+        for period in min_period..=max_period {
+            let angles = (0..window)
+                .map(|n| Decimal::TWO * Decimal::PI * Decimal::from(n) / Decimal::from(period));
+            cosine.push(angles.clone().map(|angle| angle.cos()).collect());
+            sine.push(angles.map(|angle| angle.sin()).collect());
+        }
+
+        let periods = (max_period - min_period + 1) as usize;
+
+        Self {
+            min_period,
+            window,
+            roofing: RoofingFilter::new(max_period, min_period),
+            filt: Window::new(window, Decimal::ZERO),
+            cosine,
+            sine,
+            pwr: vec![Decimal::ZERO; periods],
+            db: vec![dec!(20.0); periods],
+            dominant_cycle: Decimal::from(min_period),
+        }
+    }
+
+    /// Advances the transform by one bar and returns the unrounded dominant
+    /// cycle, for indicators that use it as their period source.
+    pub fn dominant_cycle(&mut self, price: Decimal) -> Decimal {
+        let filt = self.roofing.roof(price);
+        self.filt.push(filt);
+
+        let mut max_pwr = Decimal::ZERO;
+        for (index, pwr) in self.pwr.iter_mut().enumerate() {
+            let mut cosine_part = Decimal::ZERO;
+            let mut sine_part = Decimal::ZERO;
+            for n in 0..self.window {
+                let filt = *self.filt.get(n).unwrap_or(&Decimal::ZERO);
+                cosine_part += filt * self.cosine[index][n as usize];
+                sine_part += filt * self.sine[index][n as usize];
+            }
+            *pwr = cosine_part * cosine_part + sine_part * sine_part;
+            if *pwr > max_pwr {
+                max_pwr = *pwr;
+            }
+        }
+
+        let mut num = Decimal::ZERO;
+        let mut denom = Decimal::ZERO;
+        for (index, pwr) in self.pwr.iter_mut().enumerate() {
+            *pwr = pwr.checked_div(max_pwr).unwrap_or(Decimal::ZERO);
+
+            let db = -Decimal::TEN * (dec!(0.01) / (Decimal::ONE - dec!(0.99) * *pwr)).ln()
+                / Decimal::TEN.ln();
+            self.db[index] = db.min(dec!(20.0));
+
+            if self.db[index] < dec!(3.0) {
+                num +=
+                    Decimal::from(self.min_period as usize + index) * (dec!(3.0) - self.db[index]);
+                denom += dec!(3.0) - self.db[index];
+            }
+        }
+
+        if denom != Decimal::ZERO {
+            self.dominant_cycle = num / denom;
+        }
+
+        self.dominant_cycle
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let dominant_cycle = self.dominant_cycle(price);
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("dominant_cycle".to_string(), dominant_cycle.to_quantity());
+        for (index, (pwr, db)) in self.pwr.iter().zip(&self.db).enumerate() {
+            let period = self.min_period as usize + index;
+            result_set.insert(format!("power_{}", period), pwr.to_quantity());
+            result_set.insert(format!("db_{}", period), db.to_quantity());
+        }
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_discrete_fourier_transform_dominant_cycle() {
+        let mut dft = DiscreteFourierTransform::new(10, 48, 48);
+
+        let mut result = ResultSet::new();
+        for i in 0..150 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            result = dft.next(Decimal::from_f64(price).unwrap());
+        }
+
+        assert!(result["dominant_cycle"] > dec!(18.0) && result["dominant_cycle"] < dec!(22.0));
+        assert_eq!(result["db_20"], Decimal::ZERO);
+    }
+}
@@ -91,15 +91,9 @@ impl EmpiricalModeDecomposition {
         let peak1 = self.peak(1);
         let valley1 = self.valley(1);
 
-        let beta = (Decimal::TWO * Decimal::PI / Decimal::try_from(self.period).unwrap()).cos();
-        let gamma = Decimal::ONE
-            / (dec!(4.0) * Decimal::PI * self.delta / Decimal::try_from(self.period).unwrap())
-                .cos();
-        let alpha = gamma - (gamma.powi(2) - Decimal::ONE).sqrt().unwrap();
-
-        let bp = dec!(0.5) * (Decimal::ONE - alpha) * (price - price2)
-            + beta * (Decimal::ONE + alpha) * bp1
-            - alpha * bp2;
+        let (alpha, beta) =
+            calculate_band_pass_coefficients(Decimal::from(self.period), self.delta);
+        let bp = calculate_band_pass(price, price2, bp1, bp2, alpha, beta);
 
         let mean_bp = self.bp_sma.next(&bp.to_f64().unwrap());
 
@@ -172,3 +166,25 @@ impl EmpiricalModeDecomposition {
         result_set
     }
 }
+
+/// Band pass filter coefficients (alpha, beta) centred on the given period,
+/// where delta is half the fractional bandwidth
+pub fn calculate_band_pass_coefficients(period: Decimal, delta: Decimal) -> (Decimal, Decimal) {
+    let beta = (Decimal::TWO * Decimal::PI / period).cos();
+    let gamma = Decimal::ONE / (dec!(4.0) * Decimal::PI * delta / period).cos();
+    let alpha = gamma - (gamma.powi(2) - Decimal::ONE).sqrt().unwrap();
+
+    (alpha, beta)
+}
+
+pub fn calculate_band_pass(
+    price: Decimal,
+    price_2: Decimal,
+    bp_1: Decimal,
+    bp_2: Decimal,
+    alpha: Decimal,
+    beta: Decimal,
+) -> Decimal {
+    dec!(0.5) * (Decimal::ONE - alpha) * (price - price_2) + beta * (Decimal::ONE + alpha) * bp_1
+        - alpha * bp_2
+}
@@ -15,6 +15,12 @@ pub struct EnhancedSignalToNoiseRatio {
     snr: Window<Decimal>,
 }
 
+impl Default for EnhancedSignalToNoiseRatio {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Enhanced Signal to Noise Ratio
 /// p87 - 88 Rocket Science for Traders by John F. Ehlers
 /// A high SNR indicates a strong signal (potential trading opportunity) with
@@ -22,51 +28,23 @@ pub struct EnhancedSignalToNoiseRatio {
 /// signal with a lot of noise.
 /// Total lag for this indicator is 4 bars.
 /// Cycle mode trading should be avoided when the SNR is below 6dB
+/// method: dominant cycle measurement of the hilbert transformer, default homodyne
 impl EnhancedSignalToNoiseRatio {
     pub fn new() -> Self {
+        Self::with_method(CycleMethod::Homodyne)
+    }
+
+    pub fn with_method(method: CycleMethod) -> Self {
         Self {
-            dsp: DigitalSignalProcessor::new(),
+            dsp: DigitalSignalProcessor::with_method(method),
             noise: Window::new(2, Decimal::ZERO),
             snr: Window::new(2, Decimal::ZERO),
         }
     }
 
-    fn price(&self, i: u16) -> Decimal {
-        self.dsp.price(i)
-    }
-    fn smooth(&self, i: u16) -> Decimal {
-        self.dsp.smooth(i)
-    }
-    fn detrender(&self, i: u16) -> Decimal {
-        self.dsp.detrender(i)
-    }
-    fn i1(&self, i: u16) -> Decimal {
-        self.dsp.i1(i)
-    }
-    fn i2(&self, i: u16) -> Decimal {
-        self.dsp.i2(i)
-    }
-    fn q1(&self, i: u16) -> Decimal {
-        self.dsp.q1(i)
-    }
-    fn q2(&self, i: u16) -> Decimal {
-        self.dsp.q2(i)
-    }
     fn q3(&self, i: u16) -> Decimal {
         self.dsp.q3(i)
     }
-    fn re(&self, i: u16) -> Decimal {
-        self.dsp.re(i)
-    }
-    fn im(&self, i: u16) -> Decimal {
-        self.dsp.im(i)
-    }
-    fn period(&self, i: u16) -> Decimal {
-        self.dsp.period(i)
-    }
-    fn smooth_period(&self, i: u16) -> Decimal {
-        self.dsp.smooth_period(i)
-    }
 
     fn noise(&self, i: u16) -> Decimal {
         *self.noise.get(i - 1).unwrap_or(&Decimal::ZERO)
@@ -76,44 +54,8 @@ impl EnhancedSignalToNoiseRatio {
     }
 
     pub fn next(&mut self, price: Decimal, high: Decimal, low: Decimal) -> ResultSet {
-        let smooth = calculate_smooth(price, self.price(1), self.price(2), self.price(3));
-        let detrender = calculate_detrender(
-            smooth,
-            self.smooth(2),
-            self.smooth(4),
-            self.smooth(6),
-            self.period(1),
-        );
-        let q1 = calculate_q1(
-            detrender,
-            self.detrender(2),
-            self.detrender(4),
-            self.detrender(6),
-            self.period(1),
-        );
-        let i1 = self.detrender(3);
-        let ji = calculate_ji(i1, self.i1(1), self.i1(3), self.i1(5), self.period(1));
-        let jq = calculate_jq(q1, self.q1(1), self.q1(3), self.q1(5), self.period(1));
-        let i2 = calculate_i2(i1, jq, self.i2(1));
-        let q2 = calculate_q2(q1, ji, self.q2(1));
-        let re = calculate_re(i2, self.i2(1), q2, self.q2(1), self.re(1));
-        let im = calculate_im(i2, q2, self.i2(1), self.q2(1), self.im(1));
-        let period = calculate_period(im, re, self.period(1));
-        let smooth_period = calculate_smooth_period(period, self.smooth_period(1));
-        let q3 = calculate_q3(smooth, self.smooth(2), smooth_period);
-
-        self.dsp.price.push(price);
-        self.dsp.smooth.push(smooth);
-        self.dsp.detrender.push(detrender);
-        self.dsp.i1.push(i1);
-        self.dsp.i2.push(i2);
-        self.dsp.q1.push(q1);
-        self.dsp.q2.push(q2);
-        self.dsp.q3.push(q3);
-        self.dsp.re.push(re);
-        self.dsp.im.push(im);
-        self.dsp.period.push(period);
-        self.dsp.smooth_period.push(smooth_period);
+        let smooth_period = self.dsp.next(price);
+        let q3 = self.q3(1);
 
         let mut i3 = q3;
         for i in 1..(smooth_period.to_f64().unwrap() / 2.0).ceil() as u16 {
@@ -128,7 +70,29 @@ impl EnhancedSignalToNoiseRatio {
 
         let mut result_set = ResultSet::new();
         result_set.insert("snr".to_string(), snr.to_quantity());
+        result_set.insert("period".to_string(), smooth_period.to_quantity());
 
         result_set
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_snr_measures_sine_period() {
+        let mut snr = EnhancedSignalToNoiseRatio::new();
+
+        let mut result = ResultSet::new();
+        for i in 0..300 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            let price = Decimal::from_f64(price).unwrap();
+            result = snr.next(price, price + dec!(0.5), price - dec!(0.5));
+        }
+
+        assert!(result["period"] > dec!(18) && result["period"] < dec!(22));
+        assert!(result["snr"] > dec!(6));
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use crate::decimal::DecimalExt;
+use crate::discrete_fourier_transform::DiscreteFourierTransform;
 use rust_decimal::{Decimal, MathematicalOps};
 use rust_decimal_macros::dec;
 use yata::core::{ValueType, Window};
@@ -14,6 +15,7 @@ pub struct EvenBetterSinewave {
     hp: Window<Decimal>,
     filt: Window<Decimal>,
     duration: u16,
+    dft: Option<DiscreteFourierTransform>,
     upper_cross: Cross,
     lower_cross: Cross,
 }
@@ -23,6 +25,10 @@ pub struct EvenBetterSinewave {
 /// duration: controls maximum duration of trade when market is in a trend, default 40 bars
 /// hold a long position when the indicator is near 1.0
 /// hold a short position when the indicator is near -1.0 or close long position
+///
+/// Adaptive Even Better Sinewave
+/// the duration is set each bar to the dominant cycle measured by a discrete
+/// fourier transform spectral estimate over 10 to 48 bars
 impl EvenBetterSinewave {
     pub fn new(duration: u16) -> Self {
         Self {
@@ -30,11 +36,19 @@ impl EvenBetterSinewave {
             hp: Window::new(2, Decimal::ZERO),
             filt: Window::new(3, Decimal::ZERO),
             duration,
+            dft: None,
             upper_cross: Cross::default(),
             lower_cross: Cross::default(),
         }
     }
 
+    pub fn adaptive() -> Self {
+        Self {
+            dft: Some(DiscreteFourierTransform::new(10, 48, 48)),
+            ..Self::new(40)
+        }
+    }
+
     fn price(&self, i: u16) -> Decimal {
         *self.price.get(i - 1).unwrap_or(&Decimal::ZERO)
     }
@@ -53,9 +67,13 @@ impl EvenBetterSinewave {
         let filt2 = self.filt(2);
         let hp1 = self.hp(1);
 
-        let alpha1 = (Decimal::ONE
-            - (Decimal::TWO * Decimal::PI / Decimal::try_from(self.duration).unwrap()).sin())
-            / (Decimal::TWO * Decimal::PI / Decimal::try_from(self.duration).unwrap()).cos();
+        let duration = match self.dft.as_mut() {
+            Some(dft) => dft.dominant_cycle(price),
+            None => Decimal::from(self.duration),
+        };
+
+        let alpha1 = (Decimal::ONE - (Decimal::TWO * Decimal::PI / duration).sin())
+            / (Decimal::TWO * Decimal::PI / duration).cos();
         let alpha2 = (dec!(-1.414) * Decimal::PI / Decimal::TEN).exp();
         let beta = Decimal::TWO * alpha2 * (dec!(1.414) * Decimal::PI / Decimal::TEN).cos();
         let c2 = beta;
@@ -94,6 +112,9 @@ impl EvenBetterSinewave {
             "lower_cross".to_string(),
             Decimal::try_from(lower_cross).unwrap(),
         );
+        if self.dft.is_some() {
+            result_set.insert("duration".to_string(), duration.to_quantity());
+        }
 
         result_set
     }
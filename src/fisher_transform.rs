@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Default)]
+pub struct FisherTransform {
+    price: Window<Decimal>,
+    current_bar: u16,
+    value: Decimal,
+    fisher: Window<Decimal>,
+    cross: Cross,
+    trend: Decimal,
+    trend_since: Decimal,
+}
+
+/// Fisher Transform
+/// Using The Fisher Transform, Stocks & Commodities Nov 2002 by John F. Ehlers
+/// length: number of bars over which price is normalised, default 10
+/// price is normalised to the range -1 to 1 over the length and converted to a
+/// nearly gaussian distribution, so turning points show as sharp peaks
+/// the trigger is the fisher delayed by one bar
+impl FisherTransform {
+    pub fn new(length: u16) -> Self {
+        Self {
+            price: Window::new(length, Decimal::ZERO),
+            fisher: Window::new(1, Decimal::ZERO),
+            ..Default::default()
+        }
+    }
+
+    fn fisher(&self, i: u16) -> Decimal {
+        *self.fisher.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        self.current_bar = self.current_bar.saturating_add(1);
+        self.price.push(price);
+
+        let bars = self.current_bar.min(self.price.len());
+        let max_high = (0..bars)
+            .filter_map(|i| self.price.get(i).copied())
+            .max()
+            .unwrap_or(price);
+        let min_low = (0..bars)
+            .filter_map(|i| self.price.get(i).copied())
+            .min()
+            .unwrap_or(price);
+
+        let normalised = (price - min_low)
+            .checked_div(max_high - min_low)
+            .unwrap_or(dec!(0.5));
+        let value = (dec!(0.66) * (normalised - dec!(0.5)) + dec!(0.67) * self.value)
+            .clamp(dec!(-0.999), dec!(0.999));
+        self.value = value;
+
+        let trigger = self.fisher(1);
+        let fisher = dec!(0.5) * ((Decimal::ONE + value) / (Decimal::ONE - value)).ln()
+            + dec!(0.5) * trigger;
+
+        self.fisher.push(fisher);
+
+        let cross: Decimal = self
+            .cross
+            .next(&(
+                ValueType::try_from(fisher).unwrap(),
+                ValueType::try_from(trigger).unwrap(),
+            ))
+            .analog()
+            .into();
+
+        if cross == Decimal::ZERO {
+            self.trend_since += Decimal::ONE;
+        } else {
+            self.trend = cross;
+            self.trend_since = Decimal::ZERO;
+        }
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("fisher".to_string(), fisher.to_quantity());
+        result_set.insert("trigger".to_string(), trigger.to_quantity());
+        result_set.insert("cross".to_string(), cross);
+        result_set.insert("trend".to_string(), self.trend);
+        result_set.insert("trend_since".to_string(), self.trend_since);
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_fisher_transform_flat_price() {
+        let mut fisher = FisherTransform::new(10);
+
+        for _ in 0..20 {
+            let result = fisher.next(dec!(100));
+            assert_eq!(result["fisher"], Decimal::ZERO);
+            assert_eq!(result["cross"], Decimal::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_fisher_transform_clamps_steep_trend() {
+        let mut fisher = FisherTransform::new(10);
+
+        let mut result = ResultSet::new();
+        for i in 0..100 {
+            result = fisher.next(Decimal::from(100 + 10 * i));
+        }
+
+        let limit = dec!(1.999) / dec!(0.001);
+        assert_eq!(result["fisher"], limit.ln().to_quantity());
+    }
+
+    #[test]
+    fn test_fisher_transform_sine() {
+        let mut fisher = FisherTransform::new(10);
+
+        let mut over = Vec::new();
+        let mut under = Vec::new();
+        for i in 0..300 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            let result = fisher.next(Decimal::from_f64(price).unwrap());
+            if i >= 100 && result["cross"] == Decimal::ONE {
+                over.push(i % 20);
+            }
+            if i >= 100 && result["cross"] == -Decimal::ONE {
+                under.push(i % 20);
+            }
+        }
+
+        assert_eq!(over.len(), 10);
+        assert_eq!(under.len(), 10);
+        assert!(over.iter().all(|bar| (15..=18).contains(bar)));
+        assert!(under.iter().all(|bar| (5..=8).contains(bar)));
+    }
+}
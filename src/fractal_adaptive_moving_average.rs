@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use yata::core::Window;
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+use crate::decimal::DecimalExt;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Default)]
+pub struct FractalAdaptiveMovingAverage {
+    length: u16,
+    current_bar: u16,
+    high: Window<Decimal>,
+    low: Window<Decimal>,
+    dimension: Decimal,
+    frama: Decimal,
+    cross: Cross,
+    trend: Decimal,
+    trend_since: Decimal,
+}
+
+/// Fractal Adaptive Moving Average
+/// FRAMA - Fractal Adaptive Moving Average by John F. Ehlers
+/// length: even number of bars over which the fractal dimension is measured, default 16
+/// the fractal dimension is estimated from the high low range of each half of the
+/// length compared with the range of the whole length, it is near 1 in a trend
+/// and near 2 when prices move randomly
+/// alpha is exp(-4.6 * (dimension - 1)) bounded to 0.01 and 1, so the average
+/// follows price closely in a trend and is heavily smoothed in congestion
+/// the trend changes when price crosses the frama
+impl FractalAdaptiveMovingAverage {
+    pub fn new(length: u16) -> Self {
+        let length = length + length % 2;
+
+        Self {
+            length,
+            high: Window::new(length, Decimal::ZERO),
+            low: Window::new(length, Decimal::ZERO),
+            dimension: Decimal::ONE,
+            ..Default::default()
+        }
+    }
+
+    pub fn next(&mut self, price: Decimal, high: Decimal, low: Decimal) -> ResultSet {
+        self.current_bar = self.current_bar.saturating_add(1);
+        self.high.push(high);
+        self.low.push(low);
+
+        if let Some(dimension) = calculate_fractal_dimension(&self.high, &self.low, self.length) {
+            self.dimension = dimension;
+        }
+
+        let alpha = (dec!(-4.6) * (self.dimension - Decimal::ONE))
+            .exp()
+            .clamp(dec!(0.01), Decimal::ONE);
+
+        let frama = if self.current_bar <= self.length {
+            price
+        } else {
+            alpha * price + (Decimal::ONE - alpha) * self.frama
+        };
+        self.frama = frama;
+
+        let trend: Decimal = self
+            .cross
+            .next(&(price.to_f64().unwrap(), frama.to_f64().unwrap()))
+            .analog()
+            .into();
+
+        if trend == Decimal::ZERO {
+            self.trend_since += Decimal::ONE;
+        } else {
+            self.trend = trend;
+            self.trend_since = Decimal::ZERO;
+        }
+
+        let strength = price
+            .checked_sub(frama)
+            .unwrap_or(Decimal::ZERO)
+            .checked_div(frama)
+            .unwrap_or(Decimal::ZERO);
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("frama".to_string(), frama.to_quantity());
+        result_set.insert("dimension".to_string(), self.dimension.to_quantity());
+        result_set.insert("alpha".to_string(), alpha.to_quantity());
+        result_set.insert("trend".to_string(), self.trend);
+        result_set.insert("trend_since".to_string(), self.trend_since);
+        result_set.insert("strength".to_string(), strength.to_percent());
+
+        result_set
+    }
+}
+
+/// Fractal dimension of the latest even length of bars from the high low range of
+/// each half compared with the range of the whole, none while any range is zero
+pub fn calculate_fractal_dimension(
+    high: &Window<Decimal>,
+    low: &Window<Decimal>,
+    length: u16,
+) -> Option<Decimal> {
+    let range = |from: u16, to: u16| {
+        let highest = (from..to)
+            .filter_map(|i| high.get(i).copied())
+            .max()
+            .unwrap_or(Decimal::ZERO);
+        let lowest = (from..to)
+            .filter_map(|i| low.get(i).copied())
+            .min()
+            .unwrap_or(Decimal::ZERO);
+        highest - lowest
+    };
+
+    let half = length / 2;
+    let n1 = range(0, half) / Decimal::from(half);
+    let n2 = range(half, length) / Decimal::from(half);
+    let n3 = range(0, length) / Decimal::from(length);
+
+    if n1 > Decimal::ZERO && n2 > Decimal::ZERO && n3 > Decimal::ZERO {
+        Some(((n1 + n2).ln() - n3.ln()) / Decimal::TWO.ln())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frama_dimension_in_trend() {
+        let mut frama = FractalAdaptiveMovingAverage::new(16);
+
+        let mut result = ResultSet::new();
+        for i in 0..40 {
+            let price = Decimal::from(100 + i);
+            result = frama.next(price, price + Decimal::ONE, price - Decimal::ONE);
+        }
+
+        assert!(result["dimension"] < dec!(1.2));
+        assert!(result["alpha"] > dec!(0.4));
+    }
+}
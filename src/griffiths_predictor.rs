@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use crate::automatic_gain_control::AutomaticGainControl;
+use crate::decimal::DecimalExt;
+use crate::roofing_filter::RoofingFilter;
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use yata::core::Window;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct GriffithsPredictor {
+    length: u16,
+    mu: Decimal,
+    min_period: u16,
+    roofing: RoofingFilter,
+    agc: AutomaticGainControl,
+    signal: Window<Decimal>,
+    coef: Vec<Decimal>,
+    cosine: Vec<Vec<Decimal>>,
+    sine: Vec<Vec<Decimal>>,
+    pwr: Vec<Decimal>,
+    predict: Decimal,
+    dominant_cycle: Decimal,
+}
+
+/// Griffiths Predictor and Spectrum
+/// Linear Predictive Filters And Instantaneous Frequency, Stocks & Commodities
+/// Jan 2025 by John F. Ehlers
+/// length: number of adaptive coefficients, default 18
+/// mu: adaptation rate of the coefficients, default 1 / length
+/// min_period: shortest cycle measured, also the super smoother cutoff, default 18
+/// max_period: longest cycle measured, also the high pass cutoff, default 40
+/// the roofed price is normalised by automatic gain control and a least mean squares
+/// filter is adapted each bar to predict it from the previous length bars
+/// predict: the filter applied one bar ahead
+/// the power at each period is taken from the frequency response of the adaptive
+/// coefficients and normalised to the peak power, the dominant cycle is the centre
+/// of gravity of the periods with at least half of the peak power
+impl GriffithsPredictor {
+    pub fn new(length: u16, mu: Decimal, min_period: u16, max_period: u16) -> Self {
+        let mut cosine = Vec::new();
+        let mut sine = Vec::new();
+        for period in min_period..=max_period {
+            let angles = (1..=length).map(|count| {
+                Decimal::TWO * Decimal::PI * Decimal::from(count) / Decimal::from(period)
+            });
+            cosine.push(angles.clone().map(|angle| angle.cos()).collect());
+            sine.push(angles.map(|angle| angle.sin()).collect());
+        }
+
+        let periods = (max_period - min_period + 1) as usize;
+
+        Self {
+            length,
+            mu,
+            min_period,
+            roofing: RoofingFilter::new(max_period, min_period),
+            agc: AutomaticGainControl::new(dec!(0.991)),
+            signal: Window::new(length + 1, Decimal::ZERO),
+            coef: vec![Decimal::ZERO; length as usize],
+            cosine,
+            sine,
+            pwr: vec![Decimal::ZERO; periods],
+            predict: Decimal::ZERO,
+            dominant_cycle: Decimal::from(min_period),
+        }
+    }
+
+    fn signal(&self, i: u16) -> Decimal {
+        *self.signal.get(i).unwrap_or(&Decimal::ZERO)
+    }
+
+    /// Adapts the coefficients by one bar and returns the unrounded dominant
+    /// cycle, for indicators that use it as their period source.
+    pub fn dominant_cycle(&mut self, price: Decimal) -> Decimal {
+        let roof = self.roofing.roof(price);
+        let signal = self.agc.normalise(roof);
+        self.signal.push(signal);
+
+        let mut x_bar = Decimal::ZERO;
+        for (count, coef) in self.coef.iter().enumerate() {
+            x_bar += coef * self.signal(count as u16 + 1);
+        }
+        let error = signal - x_bar;
+        for count in 0..self.length {
+            let signal = self.signal(count + 1);
+            self.coef[count as usize] += self.mu * error * signal;
+        }
+
+        let mut predict = Decimal::ZERO;
+        for (count, coef) in self.coef.iter().enumerate() {
+            predict += coef * self.signal(count as u16);
+        }
+        self.predict = predict;
+
+        let mut max_pwr = Decimal::ZERO;
+        for (index, pwr) in self.pwr.iter_mut().enumerate() {
+            let mut real = Decimal::ZERO;
+            let mut imag = Decimal::ZERO;
+            for (count, coef) in self.coef.iter().enumerate() {
+                real += coef * self.cosine[index][count];
+                imag += coef * self.sine[index][count];
+            }
+            let denom = (Decimal::ONE - real).powi(2) + imag.powi(2);
+            *pwr = dec!(0.1).checked_div(denom).unwrap_or(Decimal::ZERO);
+            if *pwr > max_pwr {
+                max_pwr = *pwr;
+            }
+        }
+
+        let mut spx = Decimal::ZERO;
+        let mut sp = Decimal::ZERO;
+        for (index, pwr) in self.pwr.iter_mut().enumerate() {
+            *pwr = pwr.checked_div(max_pwr).unwrap_or(Decimal::ZERO);
+            if *pwr >= dec!(0.5) {
+                spx += Decimal::from(self.min_period as usize + index) * *pwr;
+                sp += *pwr;
+            }
+        }
+
+        if sp != Decimal::ZERO {
+            self.dominant_cycle = spx / sp;
+        }
+
+        self.dominant_cycle
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let dominant_cycle = self.dominant_cycle(price);
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("signal".to_string(), self.signal(0).to_quantity());
+        result_set.insert("predict".to_string(), self.predict.to_quantity());
+        result_set.insert("dominant_cycle".to_string(), dominant_cycle.to_quantity());
+        for (index, pwr) in self.pwr.iter().enumerate() {
+            let period = self.min_period as usize + index;
+            result_set.insert(format!("power_{}", period), pwr.to_quantity());
+        }
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_griffiths_predictor_dominant_cycle() {
+        let mut griffiths = GriffithsPredictor::new(18, dec!(1) / dec!(18), 18, 40);
+
+        let mut result = ResultSet::new();
+        for i in 0..400 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 25.0).sin();
+            result = griffiths.next(Decimal::from_f64(price).unwrap());
+        }
+
+        assert!(result["dominant_cycle"] > dec!(22.0) && result["dominant_cycle"] < dec!(28.0));
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::digital_signal_processor::{
+    calculate_delta_phase, calculate_sinewave, calculate_sinewave_phase,
+};
+use crate::instantaneous_trendline_filter::InstantaneousTrendlineFilter;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct HilbertTrendMode {
+    trend_fraction: Decimal,
+    phase_lower: Decimal,
+    phase_upper: Decimal,
+    deviation: Decimal,
+    itrend: InstantaneousTrendlineFilter,
+    phase: Window<Decimal>,
+    cross: Cross,
+    days_in_trend: Decimal,
+    trend_mode: Decimal,
+    trend_since: Decimal,
+}
+
+impl Default for HilbertTrendMode {
+    fn default() -> Self {
+        Self::new(dec!(0.5), dec!(0.67), dec!(1.5), dec!(0.015))
+    }
+}
+
+/// Hilbert Trend Mode
+/// Rocket Science for Traders by John F. Ehlers
+/// trend_fraction: fraction of the dominant cycle that has to pass after a sinewave
+/// crossing before a trend is declared, zero disables the rule, default 0.5
+/// phase_lower: lower bound of the phase rate of change, as a multiple of the rate
+/// expected of the dominant cycle, for the market to be in a cycle, default 0.67
+/// phase_upper: upper bound of the phase rate of change, default 1.5, a bound equal
+/// to the lower bound disables the rule
+/// deviation: distance of the smoothed price from the adaptive instantaneous
+/// trendline, as a fraction of the trendline, that declares a trend whatever the other
+/// rules, zero disables the rule, default 0.015
+/// the market is in a trend unless the sine and lead sine have crossed within the
+/// trend fraction of the dominant cycle or the phase advances at the cycle rate
+/// trend_mode: 1 when the market is in a trend and 0 when it is in a cycle
+/// trend_since: number of bars since the mode last changed
+/// deviation: distance of the smoothed price from the trendline, as a fraction of
+/// the trendline in the same unit as the deviation setting
+/// the phase and dominant cycle are taken from the hilbert transformer of the trendline
+impl HilbertTrendMode {
+    pub fn new(
+        trend_fraction: Decimal,
+        phase_lower: Decimal,
+        phase_upper: Decimal,
+        deviation: Decimal,
+    ) -> Self {
+        Self {
+            trend_fraction,
+            phase_lower,
+            phase_upper,
+            deviation,
+            itrend: InstantaneousTrendlineFilter::adaptive(),
+            phase: Window::new(1, Decimal::ZERO),
+            cross: Cross::default(),
+            days_in_trend: Decimal::ZERO,
+            trend_mode: Decimal::ZERO,
+            trend_since: Decimal::ZERO,
+        }
+    }
+
+    fn phase(&self, i: u16) -> Decimal {
+        *self.phase.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let trendline = self.itrend.trendline(price);
+        let dsp = self.itrend.dsp().unwrap();
+        let smooth_period = dsp.smooth_period(1).max(dec!(6.0));
+        let smooth_price = dsp.smooth(1);
+        let phase = calculate_sinewave_phase(dsp.i1(1), dsp.q1(1), smooth_period);
+        let delta_phase = calculate_delta_phase(phase, self.phase(1));
+
+        self.phase.push(phase);
+
+        let (sine, lead_sine) = calculate_sinewave(phase);
+
+        let cross: Decimal = self
+            .cross
+            .next(&(
+                ValueType::try_from(lead_sine).unwrap(),
+                ValueType::try_from(sine).unwrap(),
+            ))
+            .analog()
+            .into();
+
+        let mut trend_mode = Decimal::ONE;
+
+        if cross != Decimal::ZERO {
+            self.days_in_trend = Decimal::ZERO;
+            trend_mode = Decimal::ZERO;
+        }
+        self.days_in_trend += Decimal::ONE;
+        if self.days_in_trend < self.trend_fraction * smooth_period {
+            trend_mode = Decimal::ZERO;
+        }
+
+        let expected = dec!(360.0) / smooth_period;
+        if delta_phase > self.phase_lower * expected && delta_phase < self.phase_upper * expected {
+            trend_mode = Decimal::ZERO;
+        }
+
+        let deviation = (smooth_price - trendline)
+            .checked_div(trendline)
+            .unwrap_or(Decimal::ZERO)
+            .abs();
+        if self.deviation > Decimal::ZERO && deviation >= self.deviation {
+            trend_mode = Decimal::ONE;
+        }
+
+        if trend_mode == self.trend_mode {
+            self.trend_since += Decimal::ONE;
+        } else {
+            self.trend_mode = trend_mode;
+            self.trend_since = Decimal::ZERO;
+        }
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("trend_mode".to_string(), self.trend_mode);
+        result_set.insert("trend_since".to_string(), self.trend_since);
+        result_set.insert("trendline".to_string(), trendline.to_quantity());
+        result_set.insert("deviation".to_string(), deviation.to_quantity());
+        result_set.insert("period".to_string(), smooth_period.to_quantity());
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_hilbert_trend_mode() {
+        let mut mode = HilbertTrendMode::default();
+
+        let mut cycle_bars = 0;
+        for i in 0..300 {
+            let price = 100.0 + 1.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            let result = mode.next(Decimal::from_f64(price).unwrap());
+            if i >= 200 && result["trend_mode"] == Decimal::ZERO {
+                cycle_bars += 1;
+            }
+            if i >= 200 {
+                assert!(result["deviation"] < dec!(0.015));
+            }
+        }
+        assert!(cycle_bars > 80);
+
+        let mut result = ResultSet::new();
+        for i in 0..100 {
+            result = mode.next(dec!(100) + Decimal::from(i) * dec!(2));
+        }
+        assert_eq!(result["trend_mode"], Decimal::ONE);
+        assert!(result["trend_since"] > Decimal::ZERO);
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use yata::core::Window;
+
+use crate::decimal::DecimalExt;
+use crate::fractal_adaptive_moving_average::calculate_fractal_dimension;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Default)]
+pub struct HurstExponent {
+    length: u16,
+    threshold: Decimal,
+    current_bar: u16,
+    price: Window<Decimal>,
+    hurst: Decimal,
+    variance_hurst: Decimal,
+    dimension: Decimal,
+    regime: i64,
+    regime_since: i64,
+}
+
+/// Hurst Exponent
+/// Long-Term Storage Capacity Of Reservoirs by H. E. Hurst
+/// and FRAMA - Fractal Adaptive Moving Average by John F. Ehlers
+/// length: even number of bars in the rolling window, at least 16, default 100
+/// threshold: distance from 0.5 the hurst exponent has to move to change the regime, default 0.05
+/// hurst: slope of the log rescaled range of the log returns against the log of the
+/// sub window size, averaged over sub windows of length, length / 2, length / 4 and so on
+/// variance_hurst: half the slope of the log mean square of the log price differences
+/// against the log of the lag, for lags 1, 2, 4 up to a quarter of the length
+/// dimension: ehlers fractal dimension of the price over the window, near 1 in a
+/// trend and near 2 when prices move randomly
+/// the hurst exponents are held while any price in the window is not positive
+/// a hurst exponent above 0.5 indicates a persistent, trending market and below 0.5
+/// an anti persistent, mean reverting market
+/// regime: 1 when trending, -1 when mean reverting and 0 when prices move randomly
+impl HurstExponent {
+    pub fn new(length: u16, threshold: Decimal) -> Self {
+        let length = (length + length % 2).max(16);
+
+        Self {
+            length,
+            threshold,
+            price: Window::new(length + 1, Decimal::ZERO),
+            hurst: dec!(0.5),
+            variance_hurst: dec!(0.5),
+            dimension: dec!(1.5),
+            ..Default::default()
+        }
+    }
+
+    /// log prices of the window, oldest first, none while any price is not positive
+    fn log_prices(&self) -> Option<Vec<Decimal>> {
+        (0..=self.length)
+            .rev()
+            .map(|i| {
+                self.price
+                    .get(i)
+                    .filter(|price| **price > Decimal::ZERO)
+                    .map(|price| price.ln())
+            })
+            .collect()
+    }
+
+    fn calculate_rescaled_range(&self, returns: &[Decimal]) -> Option<Decimal> {
+        let mut x = Vec::new();
+        let mut y = Vec::new();
+        let mut size = returns.len();
+
+        while size >= 8 {
+            let mut total = Decimal::ZERO;
+            let mut chunks = Decimal::ZERO;
+            for chunk in returns.chunks_exact(size) {
+                let count = Decimal::from(chunk.len());
+                let mean = chunk.iter().sum::<Decimal>() / count;
+
+                let mut cumulative = Decimal::ZERO;
+                let mut max = Decimal::ZERO;
+                let mut min = Decimal::ZERO;
+                let mut squares = Decimal::ZERO;
+                for value in chunk {
+                    let deviation = value - mean;
+                    cumulative += deviation;
+                    max = max.max(cumulative);
+                    min = min.min(cumulative);
+                    squares += deviation * deviation;
+                }
+
+                let std_dev = (squares / count).sqrt().unwrap_or(Decimal::ZERO);
+                if std_dev > Decimal::ZERO {
+                    total += (max - min) / std_dev;
+                    chunks += Decimal::ONE;
+                }
+            }
+
+            if chunks > Decimal::ZERO && total > Decimal::ZERO {
+                x.push(Decimal::from(size).ln());
+                y.push((total / chunks).ln());
+            }
+            size /= 2;
+        }
+
+        calculate_slope(&x, &y)
+    }
+
+    fn calculate_variance_hurst(&self, prices: &[Decimal]) -> Option<Decimal> {
+        let mut x = Vec::new();
+        let mut y = Vec::new();
+        let mut lag = 1;
+
+        while lag <= prices.len() / 4 {
+            let differences: Vec<Decimal> = prices
+                .iter()
+                .zip(prices.iter().skip(lag))
+                .map(|(from, to)| to - from)
+                .collect();
+            let count = Decimal::from(differences.len());
+            let variance = differences
+                .iter()
+                .map(|difference| difference * difference)
+                .sum::<Decimal>()
+                / count;
+
+            if variance > Decimal::ZERO {
+                x.push(Decimal::from(lag).ln());
+                y.push(variance.ln());
+            }
+            lag *= 2;
+        }
+
+        calculate_slope(&x, &y).map(|slope| slope / Decimal::TWO)
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        self.current_bar = self.current_bar.saturating_add(1);
+        self.price.push(price);
+
+        if self.current_bar > self.length {
+            if let Some(prices) = self.log_prices() {
+                let returns: Vec<Decimal> =
+                    prices.windows(2).map(|pair| pair[1] - pair[0]).collect();
+
+                if let Some(hurst) = self.calculate_rescaled_range(&returns) {
+                    self.hurst = hurst;
+                }
+                if let Some(hurst) = self.calculate_variance_hurst(&prices) {
+                    self.variance_hurst = hurst;
+                }
+            }
+
+            if let Some(dimension) =
+                calculate_fractal_dimension(&self.price, &self.price, self.length)
+            {
+                self.dimension = dimension;
+            }
+        }
+
+        let regime = if self.hurst > dec!(0.5) + self.threshold {
+            1
+        } else if self.hurst < dec!(0.5) - self.threshold {
+            -1
+        } else {
+            0
+        };
+
+        if regime == self.regime {
+            self.regime_since += 1;
+        } else {
+            self.regime = regime;
+            self.regime_since = 0;
+        }
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("hurst".to_string(), self.hurst.to_quantity());
+        result_set.insert(
+            "variance_hurst".to_string(),
+            self.variance_hurst.to_quantity(),
+        );
+        result_set.insert("dimension".to_string(), self.dimension.to_quantity());
+        result_set.insert("regime".to_string(), Decimal::from(self.regime));
+        result_set.insert("regime_since".to_string(), Decimal::from(self.regime_since));
+
+        result_set
+    }
+}
+
+/// Least squares slope of y against x, none when there are fewer than two points
+fn calculate_slope(x: &[Decimal], y: &[Decimal]) -> Option<Decimal> {
+    if x.len() < 2 {
+        return None;
+    }
+
+    let count = Decimal::from(x.len());
+    let mean_x = x.iter().sum::<Decimal>() / count;
+    let mean_y = y.iter().sum::<Decimal>() / count;
+
+    let mut sxy = Decimal::ZERO;
+    let mut sxx = Decimal::ZERO;
+    for (x, y) in x.iter().zip(y) {
+        sxy += (x - mean_x) * (y - mean_y);
+        sxx += (x - mean_x) * (x - mean_x);
+    }
+
+    sxy.checked_div(sxx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_hurst_exponent_trend_and_mean_reversion() {
+        let mut hurst = HurstExponent::new(64, dec!(0.05));
+        let mut result = ResultSet::new();
+        for i in 0..100 {
+            let step = if i % 3 == 0 { dec!(0.5) } else { dec!(1) };
+            result = hurst.next(dec!(100) + Decimal::from(i) + step);
+        }
+        assert!(result["variance_hurst"] > dec!(0.8));
+        assert!(result["dimension"] < dec!(1.2));
+
+        let mut hurst = HurstExponent::new(64, dec!(0.05));
+        for i in 0..100 {
+            let price = 100.0 + 2.0 * (2.0 * std::f64::consts::PI * i as f64 / 7.0).sin();
+            result = hurst.next(Decimal::from_f64(price).unwrap());
+        }
+        assert!(result["variance_hurst"] < dec!(0.2));
+        assert_eq!(result["regime"], dec!(-1));
+    }
+
+    #[test]
+    fn test_hurst_exponent_holds_on_non_positive_prices() {
+        let mut hurst = HurstExponent::new(16, dec!(0.05));
+        let mut held = ResultSet::new();
+        for i in 0..30 {
+            held = hurst.next(dec!(100) + Decimal::from(i % 7));
+        }
+
+        let mut result = hurst.next(dec!(-1));
+        for i in 0..10 {
+            result = hurst.next(dec!(100) + Decimal::from(i % 5));
+        }
+
+        assert_eq!(result["hurst"], held["hurst"]);
+        assert_eq!(result["variance_hurst"], held["variance_hurst"]);
+    }
+}
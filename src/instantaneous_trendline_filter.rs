@@ -1,4 +1,6 @@
 use crate::decimal::DecimalExt;
+use crate::digital_signal_processor::DigitalSignalProcessor;
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::{Decimal, MathematicalOps};
 use rust_decimal_macros::dec;
 use std::collections::HashMap;
@@ -12,22 +14,44 @@ pub type ResultSet = HashMap<String, Decimal>;
 pub struct InstantaneousTrendlineFilter {
     price: Window<Decimal>,
     filter: Window<Decimal>,
+    itrend: Window<Decimal>,
+    dsp: Option<DigitalSignalProcessor>,
     current_bar: usize,
     cross: Cross,
     trend: Decimal,
     trend_since: Decimal,
 }
 
+/// Instantaneous Trendline Filter
+/// Cybernetic Analysis For Stocks And Futures by John F. Ehlers
+/// a two pole filter with alpha 0.07 that removes the dominant cycle component
+/// of the price with little lag
+/// the trend changes when the trigger crosses the filter
+///
+/// Adaptive Instantaneous Trendline
+/// Rocket Science For Traders by John F. Ehlers
+/// the price is averaged over the dominant cycle period measured by the hilbert
+/// transformer, which cancels the cycle component, and the average is smoothed
+/// by a four bar weighted moving average
 impl InstantaneousTrendlineFilter {
     pub fn new() -> Self {
         InstantaneousTrendlineFilter {
             price: Window::new(2, Decimal::ZERO),
             filter: Window::new(3, Decimal::ZERO),
+            itrend: Window::new(3, Decimal::ZERO),
             current_bar: 0,
             ..Default::default()
         }
     }
 
+    pub fn adaptive() -> Self {
+        Self {
+            price: Window::new(50, Decimal::ZERO),
+            dsp: Some(DigitalSignalProcessor::new()),
+            ..Self::new()
+        }
+    }
+
     fn price(&self, i: u16) -> Decimal {
         *self.price.get(i - 1).unwrap_or(&Decimal::ZERO)
     }
@@ -36,11 +60,53 @@ impl InstantaneousTrendlineFilter {
         *self.filter.get(i - 1).unwrap_or(&Decimal::ZERO)
     }
 
-    pub fn next(&mut self, price: Decimal) -> ResultSet {
-        let alpha: Decimal = dec!(0.07);
+    fn itrend(&self, i: u16) -> Decimal {
+        *self.itrend.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    /// The hilbert transformer measuring the dominant cycle of the adaptive
+    /// trendline, for indicators that also need its phase or smoothed price.
+    pub fn dsp(&self) -> Option<&DigitalSignalProcessor> {
+        self.dsp.as_ref()
+    }
+
+    /// Advances the filter by one bar and returns the unrounded trendline, for
+    /// indicators that measure price against the trendline.
+    pub fn trendline(&mut self, price: Decimal) -> Decimal {
         self.current_bar += 1;
 
-        let filter = if self.current_bar < 7 {
+        let filter = match self.dsp.as_mut() {
+            Some(dsp) => {
+                let dc_period =
+                    (dsp.next(price).round().to_u16().unwrap_or(1)).clamp(1, self.price.len() + 1);
+                let total = (1..dc_period).fold(price, |total, i| total + self.price(i));
+                let itrend = total / Decimal::from(dc_period);
+
+                let filter = if self.current_bar < 12 {
+                    price
+                } else {
+                    (dec!(4.0) * itrend
+                        + dec!(3.0) * self.itrend(1)
+                        + Decimal::TWO * self.itrend(2)
+                        + self.itrend(3))
+                        / dec!(10.0)
+                };
+                self.itrend.push(itrend);
+                filter
+            }
+            None => self.filter_price(price),
+        };
+
+        self.price.push(price);
+        self.filter.push(filter);
+
+        filter
+    }
+
+    fn filter_price(&self, price: Decimal) -> Decimal {
+        let alpha: Decimal = dec!(0.07);
+
+        if self.current_bar < 7 {
             (price + (Decimal::TWO * self.price(1)) + self.price(2)) / dec!(4.0)
         } else {
             ((alpha - (alpha.powi(2) / dec!(4.0))) * price)
@@ -48,12 +114,13 @@ impl InstantaneousTrendlineFilter {
                 - ((alpha - (dec!(0.75) * alpha.powi(2))) * self.price(2))
                 + (Decimal::TWO * (Decimal::ONE - alpha) * self.filter(1))
                 - ((Decimal::ONE - alpha).powi(2) * self.filter(2))
-        };
-
-        let trigger = (Decimal::TWO * filter) - self.filter(2);
+        }
+    }
 
-        self.price.push(price);
-        self.filter.push(filter);
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let filter_2 = self.filter(2);
+        let filter = self.trendline(price);
+        let trigger = (Decimal::TWO * filter) - filter_2;
 
         let cross: Decimal = self
             .cross
@@ -84,7 +151,31 @@ impl InstantaneousTrendlineFilter {
         result_set.insert("trend".to_string(), self.trend);
         result_set.insert("trend_since".to_string(), self.trend_since);
         result_set.insert("strength".to_string(), strength.to_percent());
+        if let Some(dsp) = self.dsp.as_ref() {
+            result_set.insert("period".to_string(), dsp.smooth_period(1).to_quantity());
+        }
 
         result_set
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_adaptive_trendline_removes_cycle() {
+        let mut itrend = InstantaneousTrendlineFilter::adaptive();
+
+        for i in 0..300 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            let result = itrend.next(Decimal::from_f64(price).unwrap());
+
+            if i > 200 {
+                assert!((result["filter"] - dec!(100)).abs() < dec!(0.5));
+                assert!(result["period"] > dec!(18) && result["period"] < dec!(22));
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Default)]
+pub struct InverseFisherTransform {
+    center: Decimal,
+    scale: Decimal,
+    length: u16,
+    value: Window<Decimal>,
+    ift: Window<Decimal>,
+    cross: Cross,
+    trend: Decimal,
+    trend_since: Decimal,
+}
+
+/// Inverse Fisher Transform
+/// The Inverse Fisher Transform, Stocks & Commodities May 2004 by John F. Ehlers
+/// center: midpoint of the wrapped oscillator, e.g. 50 for an RSI, 0 for a sinewave
+/// scale: rescales the oscillator to roughly -5 to 5, e.g. 0.1 for an RSI
+/// length: weighted moving average applied before the transform, default 9
+/// compresses any bounded oscillator towards -1 and 1 so that threshold
+/// crossings become sharp and unambiguous
+/// the trigger is the ift delayed by one bar
+///
+/// Feed it the output field of another indicator, for example
+/// `ift.next(sinewave.next(price)["signal"])`
+impl InverseFisherTransform {
+    pub fn new(center: Decimal, scale: Decimal, length: u16) -> Self {
+        Self {
+            center,
+            scale,
+            length,
+            value: Window::new(length, Decimal::ZERO),
+            ift: Window::new(1, Decimal::ZERO),
+            ..Default::default()
+        }
+    }
+
+    fn ift(&self, i: u16) -> Decimal {
+        *self.ift.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    pub fn next(&mut self, value: Decimal) -> ResultSet {
+        self.value.push(self.scale * (value - self.center));
+
+        let mut num = Decimal::ZERO;
+        let mut denom = Decimal::ZERO;
+        for count in 0..self.length {
+            let weight = Decimal::from(self.length - count);
+            num += weight * *self.value.get(count).unwrap_or(&Decimal::ZERO);
+            denom += weight;
+        }
+        let smoothed = num.checked_div(denom).unwrap_or(Decimal::ZERO);
+
+        let exp = (Decimal::TWO * smoothed.clamp(dec!(-20.0), dec!(20.0))).exp();
+        let ift = (exp - Decimal::ONE) / (exp + Decimal::ONE);
+        let trigger = self.ift(1);
+
+        self.ift.push(ift);
+
+        let cross: Decimal = self
+            .cross
+            .next(&(
+                ValueType::try_from(ift).unwrap(),
+                ValueType::try_from(trigger).unwrap(),
+            ))
+            .analog()
+            .into();
+
+        if cross == Decimal::ZERO {
+            self.trend_since += Decimal::ONE;
+        } else {
+            self.trend = cross;
+            self.trend_since = Decimal::ZERO;
+        }
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("ift".to_string(), ift.to_quantity());
+        result_set.insert("trigger".to_string(), trigger.to_quantity());
+        result_set.insert("cross".to_string(), cross);
+        result_set.insert("trend".to_string(), self.trend);
+        result_set.insert("trend_since".to_string(), self.trend_since);
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inverse_fisher_transform_rsi() {
+        let mut ift = InverseFisherTransform::new(dec!(50), dec!(0.1), 1);
+
+        assert_eq!(ift.next(dec!(50))["ift"], Decimal::ZERO);
+        assert_eq!(ift.next(dec!(90))["ift"], dec!(0.999));
+        assert_eq!(ift.next(dec!(10))["ift"], dec!(-0.999));
+    }
+}
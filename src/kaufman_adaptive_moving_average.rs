@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, MathematicalOps};
+use yata::core::Window;
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+use crate::decimal::DecimalExt;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Default)]
+pub struct KaufmanAdaptiveMovingAverage {
+    length: u16,
+    fast: Decimal,
+    slow: Decimal,
+    current_bar: u16,
+    price: Window<Decimal>,
+    kama: Decimal,
+    cross: Cross,
+    trend: Decimal,
+    trend_since: Decimal,
+}
+
+/// Kaufman Adaptive Moving Average
+/// Smarter Trading by Perry J. Kaufman
+/// length: number of bars in the efficiency ratio, default 10
+/// fast_period: ema period used when the efficiency ratio is 1, default 2
+/// slow_period: ema period used when the efficiency ratio is 0, default 30
+/// the efficiency ratio is the net change in price over the length divided by
+/// the sum of the absolute bar to bar changes, 1 in a straight line trend and
+/// near 0 in noise, and sets the smoothing constant between the slow and fast ema
+/// the trend changes when price crosses the kama
+impl KaufmanAdaptiveMovingAverage {
+    pub fn new(length: u16, fast_period: u16, slow_period: u16) -> Self {
+        Self {
+            length,
+            fast: Decimal::TWO / Decimal::from(fast_period + 1),
+            slow: Decimal::TWO / Decimal::from(slow_period + 1),
+            price: Window::new(length + 1, Decimal::ZERO),
+            ..Default::default()
+        }
+    }
+
+    fn price(&self, i: u16) -> Decimal {
+        *self.price.get(i).unwrap_or(&Decimal::ZERO)
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        self.current_bar = self.current_bar.saturating_add(1);
+        self.price.push(price);
+
+        let mut efficiency_ratio = Decimal::ZERO;
+        let kama = if self.current_bar <= self.length {
+            price
+        } else {
+            let change = (price - self.price(self.length)).abs();
+            let mut volatility = Decimal::ZERO;
+            for i in 0..self.length {
+                volatility += (self.price(i) - self.price(i + 1)).abs();
+            }
+            efficiency_ratio = change.checked_div(volatility).unwrap_or(Decimal::ZERO);
+
+            let sc = (efficiency_ratio * (self.fast - self.slow) + self.slow).powi(2);
+            self.kama + sc * (price - self.kama)
+        };
+        self.kama = kama;
+
+        let trend: Decimal = self
+            .cross
+            .next(&(price.to_f64().unwrap(), kama.to_f64().unwrap()))
+            .analog()
+            .into();
+
+        if trend == Decimal::ZERO {
+            self.trend_since += Decimal::ONE;
+        } else {
+            self.trend = trend;
+            self.trend_since = Decimal::ZERO;
+        }
+
+        let strength = price
+            .checked_sub(kama)
+            .unwrap_or(Decimal::ZERO)
+            .checked_div(kama)
+            .unwrap_or(Decimal::ZERO);
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("kama".to_string(), kama.to_quantity());
+        result_set.insert(
+            "efficiency_ratio".to_string(),
+            efficiency_ratio.to_quantity(),
+        );
+        result_set.insert("trend".to_string(), self.trend);
+        result_set.insert("trend_since".to_string(), self.trend_since);
+        result_set.insert("strength".to_string(), strength.to_percent());
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_kama_efficiency_ratio() {
+        let mut kama = KaufmanAdaptiveMovingAverage::new(10, 2, 30);
+
+        let mut result = ResultSet::new();
+        for i in 0..20 {
+            result = kama.next(Decimal::from(100 + i));
+        }
+        assert_eq!(result["efficiency_ratio"], Decimal::ONE);
+
+        for i in 0..20 {
+            result = kama.next(if i % 2 == 0 { dec!(100) } else { dec!(101) });
+        }
+        assert_eq!(result["efficiency_ratio"], Decimal::ZERO);
+    }
+}
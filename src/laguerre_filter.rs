@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Default)]
+pub struct LaguerreFilter {
+    gamma: Decimal,
+    price: Window<Decimal>,
+    l0: Window<Decimal>,
+    l1: Window<Decimal>,
+    l2: Window<Decimal>,
+    l3: Window<Decimal>,
+    cross: Cross,
+    trend: Decimal,
+    trend_since: Decimal,
+}
+
+/// Laguerre Filter
+/// Time Warp - Without Space Travel by John F. Ehlers
+/// gamma: damping factor between 0 and 1, default 0.8
+/// larger gamma values warp the four element ladder further back in time,
+/// giving more smoothing at the cost of more lag
+/// the trigger is the equivalent four bar FIR filter of the raw price
+impl LaguerreFilter {
+    pub fn new(gamma: Decimal) -> Self {
+        Self {
+            gamma,
+            price: Window::new(3, Decimal::ZERO),
+            l0: Window::new(1, Decimal::ZERO),
+            l1: Window::new(1, Decimal::ZERO),
+            l2: Window::new(1, Decimal::ZERO),
+            l3: Window::new(1, Decimal::ZERO),
+            ..Default::default()
+        }
+    }
+
+    fn price(&self, i: u16) -> Decimal {
+        *self.price.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    fn l0(&self, i: u16) -> Decimal {
+        *self.l0.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    fn l1(&self, i: u16) -> Decimal {
+        *self.l1.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    fn l2(&self, i: u16) -> Decimal {
+        *self.l2.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    fn l3(&self, i: u16) -> Decimal {
+        *self.l3.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    /// Advances the four element ladder by one bar and returns (l0, l1, l2, l3),
+    /// for indicators such as the Laguerre RSI that are built from the ladder.
+    pub fn ladder(&mut self, price: Decimal) -> (Decimal, Decimal, Decimal, Decimal) {
+        let gamma = self.gamma;
+
+        let l0 = (Decimal::ONE - gamma) * price + gamma * self.l0(1);
+        let l1 = -gamma * l0 + self.l0(1) + gamma * self.l1(1);
+        let l2 = -gamma * l1 + self.l1(1) + gamma * self.l2(1);
+        let l3 = -gamma * l2 + self.l2(1) + gamma * self.l3(1);
+
+        self.price.push(price);
+        self.l0.push(l0);
+        self.l1.push(l1);
+        self.l2.push(l2);
+        self.l3.push(l3);
+
+        (l0, l1, l2, l3)
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let trigger =
+            (price + Decimal::TWO * self.price(1) + Decimal::TWO * self.price(2) + self.price(3))
+                / dec!(6.0);
+        let (l0, l1, l2, l3) = self.ladder(price);
+        let filter = (l0 + Decimal::TWO * l1 + Decimal::TWO * l2 + l3) / dec!(6.0);
+
+        let cross: Decimal = self
+            .cross
+            .next(&(
+                ValueType::try_from(trigger).unwrap(),
+                ValueType::try_from(filter).unwrap(),
+            ))
+            .analog()
+            .into();
+
+        if cross == Decimal::ZERO {
+            self.trend_since += Decimal::ONE;
+        } else {
+            self.trend = cross;
+            self.trend_since = Decimal::ZERO;
+        }
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("filter".to_string(), filter.to_quantity());
+        result_set.insert("trigger".to_string(), trigger.to_quantity());
+        result_set.insert("cross".to_string(), cross);
+        result_set.insert("trend".to_string(), self.trend);
+        result_set.insert("trend_since".to_string(), self.trend_since);
+
+        result_set
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::laguerre_filter::LaguerreFilter;
+use rust_decimal::Decimal;
+use yata::core::ValueType;
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct LaguerreRelativeStrengthIndex {
+    laguerre: LaguerreFilter,
+    upper_cross: Cross,
+    lower_cross: Cross,
+}
+
+/// Laguerre RSI
+/// Time Warp - Without Space Travel by John F. Ehlers
+/// gamma: damping factor of the Laguerre ladder, default 0.5
+/// the RSI is taken over the four ladder elements rather than four bars,
+/// so it needs very little data and responds quickly
+/// above 0.8 the market is overbought, below 0.2 it is oversold
+impl LaguerreRelativeStrengthIndex {
+    pub fn new(gamma: Decimal) -> Self {
+        Self {
+            laguerre: LaguerreFilter::new(gamma),
+            upper_cross: Cross::default(),
+            lower_cross: Cross::default(),
+        }
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let (l0, l1, l2, l3) = self.laguerre.ladder(price);
+
+        let mut cu = Decimal::ZERO;
+        let mut cd = Decimal::ZERO;
+        for (a, b) in [(l0, l1), (l1, l2), (l2, l3)] {
+            if a >= b {
+                cu += a - b;
+            } else {
+                cd += b - a;
+            }
+        }
+
+        let rsi = cu.checked_div(cu + cd).unwrap_or(Decimal::ZERO);
+
+        let upper_cross: f64 = self
+            .upper_cross
+            .next(&(ValueType::try_from(rsi).unwrap(), 0.8))
+            .analog()
+            .into();
+        let lower_cross: f64 = self
+            .lower_cross
+            .next(&(ValueType::try_from(rsi).unwrap(), 0.2))
+            .analog()
+            .into();
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("rsi".to_string(), rsi.to_quantity());
+        result_set.insert(
+            "upper_cross".to_string(),
+            Decimal::try_from(upper_cross).unwrap(),
+        );
+        result_set.insert(
+            "lower_cross".to_string(),
+            Decimal::try_from(lower_cross).unwrap(),
+        );
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_laguerre_rsi_bounds() {
+        let mut laguerre_rsi = LaguerreRelativeStrengthIndex::new(dec!(0.5));
+
+        let mut result = ResultSet::new();
+        for i in 0..20 {
+            result = laguerre_rsi.next(Decimal::from(100 + i));
+        }
+        assert_eq!(result["rsi"], Decimal::ONE);
+
+        for i in 0..20 {
+            result = laguerre_rsi.next(Decimal::from(120 - i));
+        }
+        assert_eq!(result["rsi"], Decimal::ZERO);
+        assert_eq!(result["lower_cross"], Decimal::ZERO);
+    }
+}
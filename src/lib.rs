@@ -1,11 +1,41 @@
+pub mod adaptive_commodity_channel_index;
 pub mod adaptive_moving_average;
+pub mod adaptive_relative_strength_index;
+pub mod adaptive_stochastic;
+pub mod autocorrelation_periodogram;
+pub mod automatic_gain_control;
+pub mod center_of_gravity;
 pub mod change_percent;
 pub mod cointegration;
+pub mod comb_filter_spectrum;
+pub mod correlation_cycle;
+pub mod correlation_trend_indicator;
+pub mod cyber_cycle;
 pub mod decimal;
 pub mod digital_signal_processor;
+pub mod discrete_fourier_transform;
 pub mod empirical_mode_decomposition;
 pub mod enhanced_signal_to_noise_ratio;
 pub mod even_better_sinewave;
+pub mod fisher_transform;
+pub mod fractal_adaptive_moving_average;
+pub mod griffiths_predictor;
+pub mod hilbert_trend_mode;
+pub mod hurst_exponent;
 pub mod instantaneous_trendline_filter;
+pub mod inverse_fisher_transform;
+pub mod kaufman_adaptive_moving_average;
+pub mod laguerre_filter;
+pub mod laguerre_relative_strength_index;
+pub mod mesa_sinewave;
+pub mod mesa_stochastic;
 pub mod rate_of_change;
+pub mod reflex;
+pub mod relative_vigor_index;
+pub mod roofing_filter;
 pub mod super_smoother_filter;
+pub mod swiss_army_knife;
+pub mod trendflex;
+pub mod universal_oscillator;
+pub mod voss_predictor;
+pub mod zero_lag_exponential_moving_average;
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::digital_signal_processor::{
+    calculate_delta_phase, calculate_sinewave, calculate_sinewave_phase, DigitalSignalProcessor,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Default)]
+pub struct MesaSinewave {
+    dsp: DigitalSignalProcessor,
+    phase: Window<Decimal>,
+    cross: Cross,
+}
+
+/// MESA Sinewave
+/// Rocket Science for Traders by John F. Ehlers
+/// the phase of the dominant cycle is measured from the in phase and quadrature
+/// components of the homodyne discriminator, compensated for the seven bar lag of
+/// the smoothing, detrending and in phase delay
+/// the sine is plotted with a lead sine advanced by 45 degrees so that the
+/// crossings anticipate cycle turning points
+/// buy when the lead sine crosses over the sine, sell when it crosses under
+/// the market is in a trend when the phase advances more slowly than 0.67 or
+/// faster than 1.5 times the rate expected of the dominant cycle, in which case
+/// the crossings should be ignored
+impl MesaSinewave {
+    pub fn new() -> Self {
+        Self {
+            dsp: DigitalSignalProcessor::new(),
+            phase: Window::new(1, Decimal::ZERO),
+            ..Default::default()
+        }
+    }
+
+    fn phase(&self, i: u16) -> Decimal {
+        *self.phase.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let smooth_period = self.dsp.next(price);
+        let phase = calculate_sinewave_phase(self.dsp.i1(1), self.dsp.q1(1), smooth_period);
+        let delta_phase = calculate_delta_phase(phase, self.phase(1));
+
+        self.phase.push(phase);
+
+        let (sine, lead_sine) = calculate_sinewave(phase);
+
+        let expected = dec!(360.0) / smooth_period.max(dec!(6.0));
+        let trend_mode =
+            if delta_phase > dec!(0.67) * expected && delta_phase < dec!(1.5) * expected {
+                Decimal::ZERO
+            } else {
+                Decimal::ONE
+            };
+
+        let cross: Decimal = self
+            .cross
+            .next(&(
+                ValueType::try_from(lead_sine).unwrap(),
+                ValueType::try_from(sine).unwrap(),
+            ))
+            .analog()
+            .into();
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("sine".to_string(), sine.to_quantity());
+        result_set.insert("lead_sine".to_string(), lead_sine.to_quantity());
+        result_set.insert("phase".to_string(), phase.to_quantity());
+        result_set.insert("delta_phase".to_string(), delta_phase.to_quantity());
+        result_set.insert("cross".to_string(), cross);
+        result_set.insert("trend_mode".to_string(), trend_mode);
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_mesa_sinewave_cycle() {
+        let mut mesa_sinewave = MesaSinewave::new();
+
+        for i in 0..200 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            let result = mesa_sinewave.next(Decimal::from_f64(price).unwrap());
+
+            if i >= 100 {
+                assert_eq!(result["trend_mode"], Decimal::ZERO);
+                if result["cross"] == Decimal::ONE {
+                    assert!(price < 100.0);
+                }
+                if result["cross"] == -Decimal::ONE {
+                    assert!(price > 100.0);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::roofing_filter::RoofingFilter;
+use crate::super_smoother_filter::SuperSmootherFilter;
+use rust_decimal::Decimal;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct MesaStochastic {
+    roofing: RoofingFilter,
+    filt: Window<Decimal>,
+    smoother: SuperSmootherFilter,
+    upper_cross: Cross,
+    lower_cross: Cross,
+}
+
+/// MESA Stochastic
+/// Cycle Analytics For Traders by John F. Ehlers
+/// length: number of bars over which the roofed price is normalised, default 20
+/// the roofing filter removes trend leakage before the stochastic is taken,
+/// so it no longer sticks to the extremes in a trend, and the result is
+/// smoothed with a super smoother
+/// sell when the stochastic crosses below 0.8, buy when it crosses above 0.2
+impl MesaStochastic {
+    pub fn new(length: u16) -> Self {
+        Self {
+            roofing: RoofingFilter::new(48, 10),
+            filt: Window::new(length, Decimal::ZERO),
+            smoother: SuperSmootherFilter::new(),
+            upper_cross: Cross::default(),
+            lower_cross: Cross::default(),
+        }
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let filt = self.roofing.roof(price);
+
+        self.filt.push(filt);
+
+        let highest = self.filt.iter().copied().max().unwrap_or(filt);
+        let lowest = self.filt.iter().copied().min().unwrap_or(filt);
+
+        let stoc = (filt - lowest)
+            .checked_div(highest - lowest)
+            .unwrap_or(Decimal::ZERO);
+        let stochastic = self.smoother.smooth(stoc);
+
+        let upper_cross: f64 = self
+            .upper_cross
+            .next(&(ValueType::try_from(stochastic).unwrap(), 0.8))
+            .analog()
+            .into();
+        let lower_cross: f64 = self
+            .lower_cross
+            .next(&(ValueType::try_from(stochastic).unwrap(), 0.2))
+            .analog()
+            .into();
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("stochastic".to_string(), stochastic.to_quantity());
+        result_set.insert(
+            "upper_cross".to_string(),
+            Decimal::try_from(upper_cross).unwrap(),
+        );
+        result_set.insert(
+            "lower_cross".to_string(),
+            Decimal::try_from(lower_cross).unwrap(),
+        );
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_mesa_stochastic_cycle() {
+        let mut mesa_stochastic = MesaStochastic::new(20);
+
+        let mut upper_crosses = 0;
+        let mut lower_crosses = 0;
+        for i in 0..200 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            let result = mesa_stochastic.next(Decimal::from_f64(price).unwrap());
+
+            assert!(result["stochastic"] > -Decimal::ONE && result["stochastic"] < Decimal::TWO);
+            if result["upper_cross"] == -Decimal::ONE {
+                upper_crosses += 1;
+            }
+            if result["lower_cross"] == Decimal::ONE {
+                lower_crosses += 1;
+            }
+        }
+
+        assert!(upper_crosses >= 8);
+        assert!(lower_crosses >= 8);
+    }
+}
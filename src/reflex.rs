@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::super_smoother_filter::SuperSmootherFilter;
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct Reflex {
+    flex: Flex,
+}
+
+/// Reflex Indicator
+/// Reflex: A New Zero-Lag Indicator, Stocks & Commodities Feb 2020 by John F. Ehlers
+/// length: assumed cycle period, at least 1, default 20 bars
+/// price is smoothed by a super smoother with a critical period of half the length
+/// reflex measures the cycle component by how far the smoothed price deviates
+/// from a straight line drawn across the length, normalised by its mean square
+/// crossing above zero signals a cycle valley, crossing below zero a cycle peak
+impl Reflex {
+    pub fn new(length: u16) -> Self {
+        Self {
+            flex: Flex::new(length),
+        }
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        self.flex.next(price, "reflex", |flex, filt| {
+            let length = flex.length();
+            let slope = (flex.filt(length) - filt) / Decimal::from(length);
+            (1..=length)
+                .map(|count| (filt + Decimal::from(count) * slope) - flex.filt(count))
+                .sum()
+        })
+    }
+}
+
+/// Super smoother, mean square normalisation and zero cross shared by the
+/// Reflex and Trendflex indicators, which differ only in the sum they normalise
+pub(crate) struct Flex {
+    length: u16,
+    smoother: SuperSmootherFilter,
+    filt: Window<Decimal>,
+    ms: Decimal,
+    cross: Cross,
+    trend: Decimal,
+    trend_since: Decimal,
+}
+
+impl Flex {
+    pub(crate) fn new(length: u16) -> Self {
+        let length = length.max(1);
+
+        Self {
+            length,
+            smoother: SuperSmootherFilter::with_period((length / 2).max(1)),
+            filt: Window::new(length, Decimal::ZERO),
+            ms: Decimal::ZERO,
+            cross: Cross::default(),
+            trend: Decimal::ZERO,
+            trend_since: Decimal::ZERO,
+        }
+    }
+
+    pub(crate) fn length(&self) -> u16 {
+        self.length
+    }
+
+    pub(crate) fn filt(&self, i: u16) -> Decimal {
+        *self.filt.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    /// Smooths the price, takes the sum over the length from the smoothed price,
+    /// normalises its average by the mean square and outputs it under the key
+    pub(crate) fn next<F>(&mut self, price: Decimal, key: &str, sum: F) -> ResultSet
+    where
+        F: Fn(&Self, Decimal) -> Decimal,
+    {
+        let filt = self.smoother.smooth(price);
+        let sum = sum(self, filt) / Decimal::from(self.length);
+
+        self.ms = dec!(0.04) * sum * sum + dec!(0.96) * self.ms;
+
+        let value = if self.ms != Decimal::ZERO {
+            sum / self.ms.sqrt().unwrap()
+        } else {
+            Decimal::ZERO
+        };
+
+        self.filt.push(filt);
+
+        let cross: Decimal = self
+            .cross
+            .next(&(ValueType::try_from(value).unwrap(), 0.0))
+            .analog()
+            .into();
+
+        if cross == Decimal::ZERO {
+            self.trend_since += Decimal::ONE;
+        } else {
+            self.trend = cross;
+            self.trend_since = Decimal::ZERO;
+        }
+
+        let mut result_set = ResultSet::new();
+        result_set.insert(key.to_string(), value.to_quantity());
+        result_set.insert("cross".to_string(), cross);
+        result_set.insert("trend".to_string(), self.trend);
+        result_set.insert("trend_since".to_string(), self.trend_since);
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_reflex_sine() {
+        let mut reflex = Reflex::new(20);
+
+        let mut crosses = 0;
+        let mut amplitude = Decimal::ZERO;
+        for i in 0..300 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            let result = reflex.next(Decimal::from_f64(price).unwrap());
+            if i >= 100 {
+                amplitude = amplitude.max(result["reflex"].abs());
+                if result["cross"] != Decimal::ZERO {
+                    crosses += 1;
+                }
+            }
+        }
+
+        assert_eq!(crosses, 20);
+        assert!(amplitude > dec!(1.0) && amplitude < dec!(2.0));
+    }
+
+    #[test]
+    fn test_flex_single_bar_length() {
+        let mut reflex = Reflex::new(1);
+        let mut trendflex = crate::trendflex::Trendflex::new(1);
+
+        for i in 0..10 {
+            assert_eq!(reflex.next(Decimal::from(100 + i))["reflex"], Decimal::ZERO);
+            assert!(trendflex.next(Decimal::from(100 + i))["trendflex"] > Decimal::ZERO);
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::digital_signal_processor::DigitalSignalProcessor;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::{Candle, Method};
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Default)]
+pub struct RelativeVigorIndex {
+    length: u16,
+    dsp: Option<DigitalSignalProcessor>,
+    close_open: Window<Decimal>,
+    high_low: Window<Decimal>,
+    value1: Window<Decimal>,
+    value2: Window<Decimal>,
+    rvi: Window<Decimal>,
+    cross: Cross,
+    trend: Decimal,
+    trend_since: Decimal,
+}
+
+/// Relative Vigor Index
+/// Cybernetic Analysis for Stocks and Futures by John F. Ehlers
+/// length: number of bars summed in the numerator and denominator, default 10
+/// the vigor of a move is measured by where the close settles relative to the
+/// open, normalised by the trading range of each bar
+/// buy when the rvi crosses over the signal line, a four bar FIR of the rvi
+///
+/// Adaptive Relative Vigor Index
+/// the length is set each bar to half the smoothed dominant cycle period of the
+/// median price measured by the homodyne discriminator, up to 25 bars
+impl RelativeVigorIndex {
+    pub fn new(length: u16) -> Self {
+        Self {
+            length,
+            close_open: Window::new(3, Decimal::ZERO),
+            high_low: Window::new(3, Decimal::ZERO),
+            value1: Window::new(length, Decimal::ZERO),
+            value2: Window::new(length, Decimal::ZERO),
+            rvi: Window::new(3, Decimal::ZERO),
+            ..Default::default()
+        }
+    }
+
+    pub fn adaptive() -> Self {
+        Self {
+            dsp: Some(DigitalSignalProcessor::new()),
+            ..Self::new(25)
+        }
+    }
+
+    fn close_open(&self, i: u16) -> Decimal {
+        *self.close_open.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    fn high_low(&self, i: u16) -> Decimal {
+        *self.high_low.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    fn rvi(&self, i: u16) -> Decimal {
+        *self.rvi.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    pub fn next(&mut self, candle: &Candle) -> ResultSet {
+        let open = Decimal::from_f64(candle.open).unwrap();
+        let high = Decimal::from_f64(candle.high).unwrap();
+        let low = Decimal::from_f64(candle.low).unwrap();
+        let close = Decimal::from_f64(candle.close).unwrap();
+
+        let period = self
+            .dsp
+            .as_mut()
+            .map(|dsp| dsp.next((high + low) / Decimal::TWO));
+        let length = match period {
+            Some(period) => (period / Decimal::TWO)
+                .trunc()
+                .to_u16()
+                .unwrap()
+                .clamp(1, self.length),
+            None => self.length,
+        };
+
+        let close_open = close - open;
+        let high_low = high - low;
+
+        let value1 = (close_open
+            + Decimal::TWO * self.close_open(1)
+            + Decimal::TWO * self.close_open(2)
+            + self.close_open(3))
+            / dec!(6.0);
+        let value2 = (high_low
+            + Decimal::TWO * self.high_low(1)
+            + Decimal::TWO * self.high_low(2)
+            + self.high_low(3))
+            / dec!(6.0);
+
+        self.close_open.push(close_open);
+        self.high_low.push(high_low);
+        self.value1.push(value1);
+        self.value2.push(value2);
+
+        let mut num = Decimal::ZERO;
+        let mut denom = Decimal::ZERO;
+        for count in 0..length {
+            num += *self.value1.get(count).unwrap_or(&Decimal::ZERO);
+            denom += *self.value2.get(count).unwrap_or(&Decimal::ZERO);
+        }
+
+        let rvi = num.checked_div(denom).unwrap_or(Decimal::ZERO);
+        let signal = (rvi + Decimal::TWO * self.rvi(1) + Decimal::TWO * self.rvi(2) + self.rvi(3))
+            / dec!(6.0);
+
+        self.rvi.push(rvi);
+
+        let cross: Decimal = self
+            .cross
+            .next(&(
+                ValueType::try_from(rvi).unwrap(),
+                ValueType::try_from(signal).unwrap(),
+            ))
+            .analog()
+            .into();
+
+        if cross == Decimal::ZERO {
+            self.trend_since += Decimal::ONE;
+        } else {
+            self.trend = cross;
+            self.trend_since = Decimal::ZERO;
+        }
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("rvi".to_string(), rvi.to_quantity());
+        result_set.insert("signal".to_string(), signal.to_quantity());
+        result_set.insert("cross".to_string(), cross);
+        result_set.insert("trend".to_string(), self.trend);
+        result_set.insert("trend_since".to_string(), self.trend_since);
+        if period.is_some() {
+            result_set.insert("length".to_string(), Decimal::from(length));
+        }
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_vigor_index_bullish() {
+        let mut relative_vigor_index = RelativeVigorIndex::new(10);
+
+        let mut result = ResultSet::new();
+        for i in 0..20 {
+            let open = 100.0 + i as f64;
+            let candle = Candle {
+                open,
+                high: open + 1.5,
+                low: open - 0.5,
+                close: open + 1.0,
+                volume: 1000.0,
+            };
+            result = relative_vigor_index.next(&candle);
+        }
+
+        assert_eq!(result["rvi"], dec!(0.5));
+        assert_eq!(result["signal"], dec!(0.5));
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::super_smoother_filter::SuperSmootherFilter;
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use yata::core::Window;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct RoofingFilter {
+    hp_period: u16,
+    price: Window<Decimal>,
+    hp: Window<Decimal>,
+    smoother: SuperSmootherFilter,
+}
+
+/// Roofing Filter
+/// Cycle Analytics For Traders by John F. Ehlers
+/// hp_period: cycles longer than this are removed by a two pole high pass, default 48
+/// lp_period: cycles shorter than this are removed by a super smoother, default 10
+/// passes only the cycle components between the two periods, removing both
+/// trend leakage and aliasing noise before an oscillator is applied
+impl RoofingFilter {
+    pub fn new(hp_period: u16, lp_period: u16) -> Self {
+        Self {
+            hp_period,
+            price: Window::new(2, Decimal::ZERO),
+            hp: Window::new(2, Decimal::ZERO),
+            smoother: SuperSmootherFilter::with_period(lp_period),
+        }
+    }
+
+    fn price(&self, i: u16) -> Decimal {
+        *self.price.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    fn hp(&self, i: u16) -> Decimal {
+        *self.hp.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    /// Advances the filter by one bar and returns the unrounded roofed price.
+    pub fn roof(&mut self, price: Decimal) -> Decimal {
+        let angle = dec!(0.707) * Decimal::TWO * Decimal::PI / Decimal::from(self.hp_period);
+        let alpha1 = (angle.cos() + angle.sin() - Decimal::ONE) / angle.cos();
+
+        let hp = (Decimal::ONE - alpha1 / Decimal::TWO).powi(2)
+            * (price - Decimal::TWO * self.price(1) + self.price(2))
+            + Decimal::TWO * (Decimal::ONE - alpha1) * self.hp(1)
+            - (Decimal::ONE - alpha1).powi(2) * self.hp(2);
+
+        self.price.push(price);
+        self.hp.push(hp);
+
+        self.smoother.smooth(hp)
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let roof = self.roof(price);
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("roof".to_string(), roof.to_quantity());
+
+        result_set
+    }
+}
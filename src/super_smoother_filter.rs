@@ -11,6 +11,7 @@ pub type ResultSet = HashMap<String, Decimal>;
 
 #[derive(Default)]
 pub struct SuperSmootherFilter {
+    period: Decimal,
     price: Window<Decimal>,
     filter: Window<Decimal>,
     cross: Cross,
@@ -18,9 +19,18 @@ pub struct SuperSmootherFilter {
     trend_since: Decimal,
 }
 
+/// Super Smoother Filter
+/// p31 - 33 Cycle Analytics For Traders by John F. Ehlers
+/// period: critical period of the two pole filter, default 10 bars
+/// cycles shorter than the critical period are attenuated
 impl SuperSmootherFilter {
     pub fn new() -> Self {
+        Self::with_period(10)
+    }
+
+    pub fn with_period(period: u16) -> Self {
         SuperSmootherFilter {
+            period: Decimal::from(period),
             price: Window::new(2, Decimal::ZERO),
             filter: Window::new(3, Decimal::ZERO),
             ..Default::default()
@@ -35,21 +45,23 @@ impl SuperSmootherFilter {
         *self.filter.get(i - 1).unwrap_or(&Decimal::ZERO)
     }
 
-    pub fn next(&mut self, price: Decimal) -> ResultSet {
-        let a1 = (dec!(-1.414) * Decimal::PI / Decimal::TEN).exp();
-        let b1 =
-            Decimal::TWO * a1 * (dec!(1.414) * Decimal::TWO * Decimal::PI / Decimal::TEN).cos();
-        let c2 = b1;
-        let c3 = -a1 * a1;
-        let c1 = Decimal::ONE - c2 - c3;
+    /// Advances the filter by one bar and returns the unrounded filter value,
+    /// for indicators that use the super smoother as a stage of their own.
+    pub fn smooth(&mut self, price: Decimal) -> Decimal {
+        let (c1, c2, c3) = calculate_coefficients(self.period);
         let filter =
             c1 * (price + self.price(1)) / Decimal::TWO + c2 * self.filter(1) + c3 * self.filter(2);
 
-        let trigger = self.filter(2);
-
         self.price.push(price);
         self.filter.push(filter);
 
+        filter
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let trigger = self.filter(2);
+        let filter = self.smooth(price);
+
         let cross: Decimal = self
             .cross
             .next(&(
@@ -83,3 +95,58 @@ impl SuperSmootherFilter {
         result_set
     }
 }
+
+/// Two pole super smoother coefficients (c1, c2, c3) for the given critical period
+pub fn calculate_coefficients(period: Decimal) -> (Decimal, Decimal, Decimal) {
+    let a1 = (dec!(-1.414) * Decimal::PI / period).exp();
+    let b1 = Decimal::TWO * a1 * (dec!(1.414) * Decimal::PI / period).cos();
+    let c2 = b1;
+    let c3 = -a1 * a1;
+    let c1 = Decimal::ONE - c2 - c3;
+
+    (c1, c2, c3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_super_smoother_filter_step_response() {
+        let mut ssf = SuperSmootherFilter::new();
+
+        let filter: Vec<Decimal> = (0..6).map(|_| ssf.next(dec!(100))["filter"]).collect();
+
+        assert_eq!(
+            filter,
+            vec![
+                dec!(12.657),
+                dec!(39.972),
+                dec!(66.402),
+                dec!(85.777),
+                dec!(97.347),
+                dec!(102.777)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_super_smoother_filter_attenuates_short_cycles() {
+        let amplitude = |period: f64| {
+            let mut ssf = SuperSmootherFilter::new();
+            let mut amplitude = Decimal::ZERO;
+            for i in 0..200 {
+                let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / period).sin();
+                let filter = ssf.next(Decimal::from_f64(price).unwrap())["filter"];
+                if i >= 100 {
+                    amplitude = amplitude.max((filter - dec!(100)).abs());
+                }
+            }
+            amplitude
+        };
+
+        assert!(amplitude(40.0) > dec!(4.5));
+        assert!(amplitude(5.0) < dec!(1.5));
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::empirical_mode_decomposition::calculate_band_pass_coefficients;
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use yata::core::Window;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterType {
+    Ema,
+    Sma,
+    Gauss,
+    Butterworth,
+    Smooth,
+    HighPass,
+    TwoPoleHighPass,
+    BandPass,
+    BandStop,
+}
+
+pub struct SwissArmyKnife {
+    n: u16,
+    c0: Decimal,
+    c1: Decimal,
+    b0: Decimal,
+    b1: Decimal,
+    b2: Decimal,
+    a1: Decimal,
+    a2: Decimal,
+    price: Window<Decimal>,
+    filter: Window<Decimal>,
+}
+
+/// Swiss Army Knife Indicator
+/// Swiss Army Knife Indicator, Stocks & Commodities Jan 2006 by John F. Ehlers
+/// filter_type: response of the filter, see FilterType
+/// period: critical period of the filter, or the length of the Sma, at least 1
+/// the one pole responses, Ema and HighPass, need a period of more than 4 bars and
+/// fall back to the alpha of a conventional ema, 2 / (period + 1), below that
+/// delta: half the fractional bandwidth of the BandPass and BandStop, default 0.1
+/// every response is the same second order filter
+/// filter = c0 * (b0 * price + b1 * price[1] + b2 * price[2]) + a1 * filter[1]
+///     + a2 * filter[2] - c1 * price[n]
+/// with only the coefficients chosen by the filter type
+impl SwissArmyKnife {
+    pub fn new(filter_type: FilterType, period: u16, delta: Decimal) -> Self {
+        let period = period.max(1);
+        let angle = Decimal::TWO * Decimal::PI / Decimal::from(period);
+        let mut n = 0;
+        let mut c0 = Decimal::ONE;
+        let mut c1 = Decimal::ZERO;
+        let mut b0 = Decimal::ONE;
+        let mut b1 = Decimal::ZERO;
+        let mut b2 = Decimal::ZERO;
+        let mut a1 = Decimal::ZERO;
+        let mut a2 = Decimal::ZERO;
+
+        let one_pole_alpha = || {
+            if period > 4 {
+                (angle.cos() + angle.sin() - Decimal::ONE) / angle.cos()
+            } else {
+                Decimal::TWO / Decimal::from(period + 1)
+            }
+        };
+        let two_pole_alpha = || {
+            let beta = dec!(2.415) * (Decimal::ONE - angle.cos());
+            -beta + (beta * beta + Decimal::TWO * beta).sqrt().unwrap()
+        };
+
+        match filter_type {
+            FilterType::Ema => {
+                let one_pole_alpha = one_pole_alpha();
+                b0 = one_pole_alpha;
+                a1 = Decimal::ONE - one_pole_alpha;
+            }
+            FilterType::Sma => {
+                n = period;
+                c1 = Decimal::ONE / Decimal::from(period);
+                b0 = Decimal::ONE / Decimal::from(period);
+                a1 = Decimal::ONE;
+            }
+            FilterType::Gauss => {
+                let two_pole_alpha = two_pole_alpha();
+                c0 = two_pole_alpha * two_pole_alpha;
+                a1 = Decimal::TWO * (Decimal::ONE - two_pole_alpha);
+                a2 = -(Decimal::ONE - two_pole_alpha).powi(2);
+            }
+            FilterType::Butterworth => {
+                let two_pole_alpha = two_pole_alpha();
+                c0 = two_pole_alpha * two_pole_alpha / dec!(4.0);
+                b1 = Decimal::TWO;
+                b2 = Decimal::ONE;
+                a1 = Decimal::TWO * (Decimal::ONE - two_pole_alpha);
+                a2 = -(Decimal::ONE - two_pole_alpha).powi(2);
+            }
+            FilterType::Smooth => {
+                c0 = dec!(0.25);
+                b1 = Decimal::TWO;
+                b2 = Decimal::ONE;
+            }
+            FilterType::HighPass => {
+                let one_pole_alpha = one_pole_alpha();
+                c0 = Decimal::ONE - one_pole_alpha / Decimal::TWO;
+                b1 = -Decimal::ONE;
+                a1 = Decimal::ONE - one_pole_alpha;
+            }
+            FilterType::TwoPoleHighPass => {
+                let two_pole_alpha = two_pole_alpha();
+                c0 = (Decimal::ONE - two_pole_alpha / Decimal::TWO).powi(2);
+                b1 = -Decimal::TWO;
+                b2 = Decimal::ONE;
+                a1 = Decimal::TWO * (Decimal::ONE - two_pole_alpha);
+                a2 = -(Decimal::ONE - two_pole_alpha).powi(2);
+            }
+            FilterType::BandPass => {
+                let (alpha, beta) = calculate_band_pass_coefficients(Decimal::from(period), delta);
+                c0 = (Decimal::ONE - alpha) / Decimal::TWO;
+                b2 = -Decimal::ONE;
+                a1 = beta * (Decimal::ONE + alpha);
+                a2 = -alpha;
+            }
+            FilterType::BandStop => {
+                let (alpha, beta) = calculate_band_pass_coefficients(Decimal::from(period), delta);
+                c0 = (Decimal::ONE + alpha) / Decimal::TWO;
+                b1 = -Decimal::TWO * beta;
+                b2 = Decimal::ONE;
+                a1 = beta * (Decimal::ONE + alpha);
+                a2 = -alpha;
+            }
+        }
+
+        Self {
+            n,
+            c0,
+            c1,
+            b0,
+            b1,
+            b2,
+            a1,
+            a2,
+            price: Window::new(n.max(2), Decimal::ZERO),
+            filter: Window::new(2, Decimal::ZERO),
+        }
+    }
+
+    fn price(&self, i: u16) -> Decimal {
+        *self.price.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    fn filter(&self, i: u16) -> Decimal {
+        *self.filter.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    /// Advances the filter by one bar and returns the unrounded filter value.
+    pub fn filt(&mut self, price: Decimal) -> Decimal {
+        let price_n = if self.n == 0 {
+            price
+        } else {
+            self.price(self.n)
+        };
+
+        let filter = self.c0
+            * (self.b0 * price + self.b1 * self.price(1) + self.b2 * self.price(2))
+            + self.a1 * self.filter(1)
+            + self.a2 * self.filter(2)
+            - self.c1 * price_n;
+
+        self.price.push(price);
+        self.filter.push(filter);
+
+        filter
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let filter = self.filt(price);
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("filter".to_string(), filter.to_quantity());
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swiss_army_knife_sma() {
+        let mut sma = SwissArmyKnife::new(FilterType::Sma, 4, dec!(0.1));
+
+        let mut result = ResultSet::new();
+        for price in [1, 2, 3, 4, 5, 6] {
+            result = sma.next(Decimal::from(price));
+        }
+
+        assert_eq!(result["filter"], dec!(4.5));
+    }
+
+    #[test]
+    fn test_swiss_army_knife_dc_response() {
+        for (filter_type, gain) in [
+            (FilterType::Ema, Decimal::ONE),
+            (FilterType::Gauss, Decimal::ONE),
+            (FilterType::Butterworth, Decimal::ONE),
+            (FilterType::Smooth, Decimal::ONE),
+            (FilterType::HighPass, Decimal::ZERO),
+            (FilterType::TwoPoleHighPass, Decimal::ZERO),
+            (FilterType::BandPass, Decimal::ZERO),
+            (FilterType::BandStop, Decimal::ONE),
+        ] {
+            for period in [20, 4, 2] {
+                let mut filter = SwissArmyKnife::new(filter_type, period, dec!(0.1));
+
+                let mut result = ResultSet::new();
+                for _ in 0..500 {
+                    result = filter.next(Decimal::ONE_HUNDRED);
+                }
+
+                assert_eq!(
+                    result["filter"],
+                    gain * Decimal::ONE_HUNDRED,
+                    "{:?} {}",
+                    filter_type,
+                    period
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use crate::reflex::Flex;
+use rust_decimal::Decimal;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct Trendflex {
+    flex: Flex,
+}
+
+/// Trendflex Indicator
+/// Reflex: A New Zero-Lag Indicator, Stocks & Commodities Feb 2020 by John F. Ehlers
+/// length: assumed cycle period, at least 1, default 20 bars
+/// price is smoothed by a super smoother with a critical period of half the length
+/// trendflex measures the trend component by how far the smoothed price has moved
+/// from each of its values across the length, normalised by its mean square
+/// crossing above zero signals the start of an uptrend, crossing below zero a downtrend
+impl Trendflex {
+    pub fn new(length: u16) -> Self {
+        Self {
+            flex: Flex::new(length),
+        }
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        self.flex.next(price, "trendflex", |flex, filt| {
+            (1..=flex.length())
+                .map(|count| filt - flex.filt(count))
+                .sum()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_trendflex_uptrend() {
+        let mut trendflex = Trendflex::new(20);
+
+        for i in 0..50 {
+            trendflex.next(Decimal::from(200 - i));
+        }
+
+        let mut result = ResultSet::new();
+        for i in 0..100 {
+            result = trendflex.next(Decimal::from(150 + i));
+        }
+
+        assert!(result["trendflex"] > dec!(0.5));
+        assert_eq!(result["trend"], Decimal::ONE);
+    }
+}
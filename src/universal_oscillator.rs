@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use crate::automatic_gain_control::AutomaticGainControl;
+use crate::decimal::DecimalExt;
+use crate::super_smoother_filter::SuperSmootherFilter;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+pub struct UniversalOscillator {
+    threshold: f64,
+    price: Window<Decimal>,
+    ssf: SuperSmootherFilter,
+    agc: AutomaticGainControl,
+    upper_cross: Cross,
+    lower_cross: Cross,
+}
+
+/// Universal Oscillator
+/// Whiter Is Brighter, Stocks & Commodities Jan 2015 by John F. Ehlers
+/// band_edge: critical period of the super smoother, default 20 bars
+/// threshold: level of the upper and lower crosses, default 0.8
+/// the two bar price difference whitens the spectrum of the prices, it is smoothed
+/// by a super smoother and normalised to swing between -1 and 1 by automatic gain control
+/// a shorter band edge makes the oscillator faster, a longer one smoother
+/// buy when the oscillator crosses over the lower threshold
+/// sell when the oscillator crosses under the upper threshold
+impl UniversalOscillator {
+    pub fn new(band_edge: u16, threshold: Decimal) -> Self {
+        Self {
+            threshold: threshold.to_f64().unwrap(),
+            price: Window::new(2, Decimal::ZERO),
+            ssf: SuperSmootherFilter::with_period(band_edge),
+            agc: AutomaticGainControl::new(dec!(0.991)),
+            upper_cross: Cross::default(),
+            lower_cross: Cross::default(),
+        }
+    }
+
+    fn price(&self, i: u16) -> Decimal {
+        *self.price.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        let price2 = self.price(2);
+        let white_noise = if price2 == Decimal::ZERO {
+            Decimal::ZERO
+        } else {
+            (price - price2) / Decimal::TWO
+        };
+        self.price.push(price);
+
+        let filt = self.ssf.smooth(white_noise);
+        let universal = self.agc.normalise(filt);
+
+        let upper_cross: f64 = self
+            .upper_cross
+            .next(&(ValueType::try_from(universal).unwrap(), self.threshold))
+            .analog()
+            .into();
+        let lower_cross: f64 = self
+            .lower_cross
+            .next(&(ValueType::try_from(universal).unwrap(), -self.threshold))
+            .analog()
+            .into();
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("universal".to_string(), universal.to_quantity());
+        result_set.insert(
+            "upper_cross".to_string(),
+            Decimal::try_from(upper_cross).unwrap(),
+        );
+        result_set.insert(
+            "lower_cross".to_string(),
+            Decimal::try_from(lower_cross).unwrap(),
+        );
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    #[test]
+    fn test_universal_oscillator_swings_between_thresholds() {
+        let mut universal = UniversalOscillator::new(20, dec!(0.8));
+
+        let mut upper_crosses = 0;
+        let mut lower_crosses = 0;
+        for i in 0..200 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 40.0).sin();
+            let result = universal.next(Decimal::from_f64(price).unwrap());
+
+            assert!(result["universal"].abs() <= Decimal::ONE);
+            if result["upper_cross"] == dec!(-1) {
+                upper_crosses += 1;
+            }
+            if result["lower_cross"] == dec!(1) {
+                lower_crosses += 1;
+            }
+        }
+
+        assert!(upper_crosses >= 3);
+        assert!(lower_crosses >= 3);
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use crate::empirical_mode_decomposition::{calculate_band_pass, calculate_band_pass_coefficients};
+use rust_decimal::Decimal;
+use yata::core::{ValueType, Window};
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Default)]
+pub struct VossPredictor {
+    alpha: Decimal,
+    beta: Decimal,
+    order: u16,
+    current_bar: usize,
+    price: Window<Decimal>,
+    filt: Window<Decimal>,
+    voss: Window<Decimal>,
+    cross: Cross,
+    trend: Decimal,
+    trend_since: Decimal,
+}
+
+/// Voss Predictive Filter
+/// A Peek Into The Future, Stocks & Commodities Aug 2019 by John F. Ehlers
+/// period: centre period of the band pass filter, default 20
+/// bandwidth: fractional bandwidth of the band pass filter, default 0.25
+/// predict: number of bars of lead given by the voss filter, default 3, 0 gives no lead
+/// the band limited price is passed through a filter with negative group delay,
+/// so the voss leads the filter by a few bars on a clean cycle
+/// buy when the voss crosses over the filter, sell when it crosses under
+impl VossPredictor {
+    pub fn new(period: u16, bandwidth: Decimal, predict: u16) -> Self {
+        let (alpha, beta) =
+            calculate_band_pass_coefficients(Decimal::from(period), bandwidth / Decimal::TWO);
+        let order = 3 * predict;
+
+        Self {
+            alpha,
+            beta,
+            order,
+            price: Window::new(2, Decimal::ZERO),
+            filt: Window::new(2, Decimal::ZERO),
+            voss: Window::new(order.max(1), Decimal::ZERO),
+            ..Default::default()
+        }
+    }
+
+    fn price(&self, i: u16) -> Decimal {
+        *self.price.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    fn filt(&self, i: u16) -> Decimal {
+        *self.filt.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    fn voss(&self, i: u16) -> Decimal {
+        *self.voss.get(i - 1).unwrap_or(&Decimal::ZERO)
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        self.current_bar += 1;
+
+        let filt = if self.current_bar <= 5 {
+            Decimal::ZERO
+        } else {
+            calculate_band_pass(
+                price,
+                self.price(2),
+                self.filt(1),
+                self.filt(2),
+                self.alpha,
+                self.beta,
+            )
+        };
+
+        let order = Decimal::from(self.order);
+        let mut sum_c = Decimal::ZERO;
+        for count in 0..self.order {
+            sum_c += Decimal::from(count + 1) / order * self.voss(self.order - count);
+        }
+        let voss = (Decimal::from(3 + self.order) / Decimal::TWO) * filt - sum_c;
+
+        self.price.push(price);
+        self.filt.push(filt);
+        self.voss.push(voss);
+
+        let cross: Decimal = self
+            .cross
+            .next(&(
+                ValueType::try_from(voss).unwrap(),
+                ValueType::try_from(filt).unwrap(),
+            ))
+            .analog()
+            .into();
+
+        if cross == Decimal::ZERO {
+            self.trend_since += Decimal::ONE;
+        } else {
+            self.trend = cross;
+            self.trend_since = Decimal::ZERO;
+        }
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("filter".to_string(), filt.to_quantity());
+        result_set.insert("voss".to_string(), voss.to_quantity());
+        result_set.insert("cross".to_string(), cross);
+        result_set.insert("trend".to_string(), self.trend);
+        result_set.insert("trend_since".to_string(), self.trend_since);
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::correlation_trend_indicator::calculate_correlation;
+    use rust_decimal::prelude::FromPrimitive;
+    use rust_decimal_macros::dec;
+
+    fn run(predict: u16) -> (Vec<Decimal>, Vec<Decimal>) {
+        let mut voss = VossPredictor::new(20, dec!(0.25), predict);
+
+        let mut filter = Vec::new();
+        let mut prediction = Vec::new();
+        for i in 0..300 {
+            let price = 100.0 + 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 20.0).sin();
+            let result = voss.next(Decimal::from_f64(price).unwrap());
+            if i >= 100 {
+                filter.push(result["filter"]);
+                prediction.push(result["voss"]);
+            }
+        }
+
+        (filter, prediction)
+    }
+
+    fn lead(filter: &[Decimal], prediction: &[Decimal]) -> usize {
+        (0..10)
+            .max_by_key(|&lag| {
+                let n = filter.len() - lag;
+                calculate_correlation(&prediction[..n], &filter[lag..])
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_voss_predictor_leads_filter() {
+        let (filter, prediction) = run(3);
+        assert_eq!(lead(&filter, &prediction), 3);
+    }
+
+    #[test]
+    fn test_voss_predictor_without_prediction() {
+        let (filter, prediction) = run(0);
+        assert_eq!(lead(&filter, &prediction), 0);
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use crate::decimal::DecimalExt;
+use rust_decimal::Decimal;
+use yata::core::ValueType;
+use yata::methods::Cross;
+use yata::prelude::Method;
+
+pub type ResultSet = HashMap<String, Decimal>;
+
+#[derive(Default)]
+pub struct ZeroLagExponentialMovingAverage {
+    alpha: Decimal,
+    gain_limit: i64,
+    current_bar: usize,
+    ema: Decimal,
+    ec: Decimal,
+    cross: Cross,
+    trend: Decimal,
+    trend_since: Decimal,
+}
+
+/// Zero Lag (Error Correcting) Exponential Moving Average
+/// Zero Lag (Well, Almost) by John F. Ehlers and Ric Way
+/// length: length of the ema, default 20
+/// gain_limit: the gain is searched from -gain_limit / 10 to gain_limit / 10, default 50
+/// each bar the ec adds a gain times the error between price and the previous ec
+/// to the ema, choosing the gain that leaves the smallest error
+/// buy when the ec crosses over the ema, sell when it crosses under
+impl ZeroLagExponentialMovingAverage {
+    pub fn new(length: u16, gain_limit: u16) -> Self {
+        Self {
+            alpha: Decimal::TWO / Decimal::from(length + 1),
+            gain_limit: i64::from(gain_limit),
+            ..Default::default()
+        }
+    }
+
+    pub fn next(&mut self, price: Decimal) -> ResultSet {
+        self.current_bar += 1;
+        if self.current_bar == 1 {
+            self.ema = price;
+            self.ec = price;
+        }
+
+        let alpha = self.alpha;
+        let ec1 = self.ec;
+        let ema = alpha * price + (Decimal::ONE - alpha) * self.ema;
+
+        let mut least_error = Decimal::MAX;
+        let mut best_gain = Decimal::ZERO;
+        for value in -self.gain_limit..=self.gain_limit {
+            let gain = Decimal::new(value, 1);
+            let ec = alpha * (ema + gain * (price - ec1)) + (Decimal::ONE - alpha) * ec1;
+            let error = (price - ec).abs();
+            if error < least_error {
+                least_error = error;
+                best_gain = gain;
+            }
+        }
+
+        let ec = alpha * (ema + best_gain * (price - ec1)) + (Decimal::ONE - alpha) * ec1;
+
+        self.ema = ema;
+        self.ec = ec;
+
+        let cross: Decimal = self
+            .cross
+            .next(&(
+                ValueType::try_from(ec).unwrap(),
+                ValueType::try_from(ema).unwrap(),
+            ))
+            .analog()
+            .into();
+
+        if cross == Decimal::ZERO {
+            self.trend_since += Decimal::ONE;
+        } else {
+            self.trend = cross;
+            self.trend_since = Decimal::ZERO;
+        }
+
+        let strength = ec
+            .checked_sub(ema)
+            .unwrap_or(Decimal::ZERO)
+            .checked_div(ema)
+            .unwrap_or(Decimal::ZERO);
+
+        let mut result_set = ResultSet::new();
+        result_set.insert("ema".to_string(), ema.to_quantity());
+        result_set.insert("ec".to_string(), ec.to_quantity());
+        result_set.insert("gain".to_string(), best_gain);
+        result_set.insert("cross".to_string(), cross);
+        result_set.insert("trend".to_string(), self.trend);
+        result_set.insert("trend_since".to_string(), self.trend_since);
+        result_set.insert("strength".to_string(), strength.to_percent());
+
+        result_set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_zero_lag_ema_tracks_trend() {
+        let mut zlema = ZeroLagExponentialMovingAverage::new(20, 50);
+
+        let mut result = ResultSet::new();
+        for i in 0..50 {
+            result = zlema.next(Decimal::from(100 + i));
+        }
+
+        assert!(result["ec"] > result["ema"]);
+        assert!(dec!(149) - result["ec"] < dec!(3));
+        assert!(result["gain"] > Decimal::ZERO);
+    }
+}